@@ -0,0 +1,93 @@
+use ink::{primitives::AccountId, scale::Decode};
+use ink_e2e::{ChainBackend, ContractsBackend, E2EBackend};
+
+use super::*;
+use crate::fungibles::{Faucet, FaucetRef};
+
+type Balance = u128;
+type BlockNumber = u32;
+type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const UNIT: Balance = 1_000_000_000_000;
+const INIT_VALUE: Balance = 1_000 * UNIT;
+const DRIP_AMOUNT: Balance = 10 * UNIT;
+const COOLDOWN: BlockNumber = 2;
+
+#[ink_e2e::test]
+async fn drip_works<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+	let mut constructor = FaucetRef::new(COOLDOWN, DRIP_AMOUNT);
+	let contract = client
+		.instantiate("faucet", &ink_e2e::alice(), &mut constructor)
+		.value(INIT_VALUE)
+		.submit()
+		.await
+		.expect("instantiate failed");
+	let mut call_builder = contract.call_builder::<Faucet>();
+
+	let start_stop = call_builder.start_stop();
+	client
+		.call(&ink_e2e::alice(), &start_stop)
+		.submit()
+		.await
+		.expect("start_stop failed");
+
+	let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+	let bob_before = client.free_balance(bob).await?;
+	let faucet_before = client.free_balance(contract.account_id).await?;
+
+	let drip = call_builder.drip();
+	let result = client.call(&ink_e2e::bob(), &drip).submit().await.expect("drip failed");
+
+	// Faucet paid exactly the drip amount, Bob received it minus the transaction fees.
+	let bob_after = client.free_balance(bob).await?;
+	assert_eq!(client.free_balance(contract.account_id).await?, faucet_before - DRIP_AMOUNT);
+	assert!(bob_after > bob_before && bob_after <= bob_before + DRIP_AMOUNT);
+
+	// A `Drip { value, to }` event has been emitted.
+	let events = result.contract_emitted_events()?;
+	let drip_event = events.last().expect("drip event");
+	let (value, to) = <(Balance, AccountId)>::decode(&mut &drip_event.event.data[..])?;
+	assert_eq!((value, to), (DRIP_AMOUNT, bob));
+
+	Ok(())
+}
+
+#[ink_e2e::test]
+async fn drip_respects_cooldown_across_blocks<Client: E2EBackend>(
+	mut client: Client,
+) -> E2EResult<()> {
+	let mut constructor = FaucetRef::new(COOLDOWN, DRIP_AMOUNT);
+	let contract = client
+		.instantiate("faucet", &ink_e2e::alice(), &mut constructor)
+		.value(INIT_VALUE)
+		.submit()
+		.await
+		.expect("instantiate failed");
+	let mut call_builder = contract.call_builder::<Faucet>();
+
+	// Inactive faucet does not drip.
+	let drip = call_builder.drip();
+	let result = client.call(&ink_e2e::bob(), &drip).dry_run().await?;
+	assert_eq!(result.return_value(), Err(FaucetError::NotActive));
+
+	let start_stop = call_builder.start_stop();
+	client
+		.call(&ink_e2e::alice(), &start_stop)
+		.submit()
+		.await
+		.expect("start_stop failed");
+
+	client.call(&ink_e2e::bob(), &drip).submit().await.expect("drip failed");
+	// Bob is in cooldown right after dripping.
+	let result = client.call(&ink_e2e::bob(), &drip).dry_run().await?;
+	assert!(matches!(result.return_value(), Err(FaucetError::InCoolDown { .. })));
+
+	// Each submitted extrinsic is sealed in its own block, so these drips move the chain
+	// past Bob's cooldown.
+	client.call(&ink_e2e::charlie(), &drip).submit().await.expect("drip failed");
+	client.call(&ink_e2e::dave(), &drip).submit().await.expect("drip failed");
+
+	client.call(&ink_e2e::bob(), &drip).submit().await.expect("drip failed");
+
+	Ok(())
+}
@@ -1,42 +1,766 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
 use ink::{
-	storage::Mapping,
+	env::hash::Blake2x256,
+	prelude::{vec, vec::Vec},
+	storage::{Lazy, Mapping},
+	xcm::{
+		v4::{
+			Asset, AssetFilter, Instruction, Junction, Junctions, Location, Parent, WeightLimit,
+			WildAsset, Xcm,
+		},
+		VersionedLocation, VersionedXcm,
+	},
 };
+use pop_api::{
+	primitives::TokenId,
+	v0::fungibles as api,
+	StatusCode,
+};
+
+#[cfg(test)]
+mod tests;
+#[cfg(all(test, feature = "e2e-tests"))]
+mod e2e_tests;
+
+type Balance = <ink::env::DefaultEnvironment as ink::env::Environment>::Balance;
+type BlockNumber = <ink::env::DefaultEnvironment as ink::env::Environment>::BlockNumber;
+type Timestamp = <ink::env::DefaultEnvironment as ink::env::Environment>::Timestamp;
+/// Length of a cooldown, in blocks or milliseconds depending on the `CooldownUnit`.
+type Duration = Timestamp;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
 pub enum FaucetError {
-	InCoolDown,
+	/// Requester must wait until `available_at` before requesting again.
+	InCoolDown { available_at: Instant },
 	NotActive,
-	NotEnoughFunds,
+	/// Faucet holds `available` tokens but needs a balance of `required` to pay out.
+	NotEnoughFunds { available: Balance, required: Balance },
 	NotOwner,
 	ValueTooLarge,
+	AssetNotRegistered,
+	StatusCode(u32),
+	InvalidLocation,
+	XcmExecutionFailed,
+	MissingRole,
+	NoPendingOwner,
+	NotPendingOwner,
+	/// Pending ownership transfer could only be accepted until block `expired_at`.
+	OwnershipTransferExpired { expired_at: BlockNumber },
+	/// Only `remaining` tokens can be dripped until the budget resets at block `resets_at`.
+	GlobalBudgetExhausted { remaining: Balance, resets_at: BlockNumber },
+	NotAllowlisted,
+	/// Requester is denylisted for `reason` until block `expires_at`, or indefinitely.
+	Denylisted { reason: u32, expires_at: Option<BlockNumber> },
+	InvalidSignature,
+	UnknownAttester,
+	/// Voucher could only be redeemed until block `expired_at`.
+	VoucherExpired { expired_at: BlockNumber },
+	VoucherAlreadyUsed,
+	TransferFailed,
+	BelowExistentialDeposit,
+	LifetimeQuotaExhausted,
+	/// Requester reached the drips allowed per window and must wait until block `available_at`.
+	WindowQuotaExhausted { available_at: BlockNumber },
+	/// Requester has only accrued `allowance` tokens in its bucket.
+	AllowanceExceeded { allowance: Balance },
+	/// Requested amount is above the `maximum` allowed per request.
+	AmountAboveMaximum { maximum: Balance },
+	RefillNotConfigured,
+	/// Refills of the current period reached their cap, which resets at block `resets_at`.
+	RefillCapExhausted { resets_at: BlockNumber },
+	/// Minting the drip would bring the supply of the asset above its `cap`.
+	SupplyCapReached { cap: Balance },
+	/// Periods must last at least one block.
+	InvalidPeriod,
+	/// Amount to drip is zero.
+	ZeroAmount,
+	/// Window quota allows more than `MAX_WINDOW_DRIPS` drips.
+	InvalidQuota,
+}
+
+/// Permissions that can be granted to accounts to administer the faucet.
+/// The owner of the contract implicitly holds every role.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum Role {
+	/// Can grant and revoke roles, and act as any other role.
+	Admin,
+	/// Can activate and deactivate the faucet.
+	Operator,
+	/// Can withdraw funds from the faucet.
+	Treasurer,
+	/// Can change the drip configuration of the faucet.
+	ConfigManager,
+}
+
+/// Which accounts are eligible to drip.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum AccessMode {
+	/// Any account can drip.
+	#[default]
+	Open,
+	/// Any account that is not denylisted can drip.
+	Denylist,
+	/// Only allowlisted accounts that are not denylisted can drip.
+	Allowlist,
+}
+
+/// Unit cooldowns are counted in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum CooldownUnit {
+	/// Cooldowns are a number of blocks.
+	#[default]
+	Blocks,
+	/// Cooldowns are a number of milliseconds, measured with the block timestamp.
+	Milliseconds,
+}
+
+/// A point in time, counted in one of the cooldown units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum Instant {
+	/// Block number.
+	Block(BlockNumber),
+	/// Block timestamp, in milliseconds.
+	Timestamp(Timestamp),
+}
+
+impl Instant {
+	/// Unit this instant is counted in.
+	pub fn unit(&self) -> CooldownUnit {
+		match self {
+			Instant::Block(_) => CooldownUnit::Blocks,
+			Instant::Timestamp(_) => CooldownUnit::Milliseconds,
+		}
+	}
+
+	/// This instant, `duration` units later.
+	fn saturating_add(self, duration: Duration) -> Self {
+		match self {
+			Instant::Block(block) => Instant::Block(
+				block.saturating_add(BlockNumber::try_from(duration).unwrap_or(BlockNumber::MAX)),
+			),
+			Instant::Timestamp(timestamp) => Instant::Timestamp(timestamp.saturating_add(duration)),
+		}
+	}
+}
+
+impl From<StatusCode> for FaucetError {
+	fn from(value: StatusCode) -> Self {
+		FaucetError::StatusCode(value.0)
+	}
 }
 
 #[ink::contract]
 mod fungibles {
 	use super::*;
 
+	/// Largest `WindowQuota::max_drips`, bounding the drips tracked per account.
+	const MAX_WINDOW_DRIPS: u32 = 64;
+	/// Number of donors kept in the `top_donors` leaderboard.
+	const MAX_TOP_DONORS: usize = 10;
+	/// Default length of the window the drip rate is observed over, a day of 6s blocks.
+	const DEFAULT_STATS_WINDOW: BlockNumber = 14_400;
+
+	/// Drip parameters of a fungible asset registered in the faucet.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct AssetConfig {
+		/// Amount of the asset to drip per request.
+		pub drip_amount: Balance,
+		/// Number of blocks, or milliseconds, an account should wait between requests of this
+		/// asset, depending on the faucet's `cooldown_unit`.
+		pub cooldown: Duration,
+	}
+
+	/// Minting of a fungible asset the faucet drips by minting rather than transferring.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct MintConfig {
+		/// Total supply the asset cannot exceed through drips, if any.
+		pub supply_cap: Option<Balance>,
+	}
+
+	/// Metadata of a fungible asset.
+	#[derive(Clone, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	pub struct AssetMetadata {
+		/// Name of the asset.
+		pub name: Vec<u8>,
+		/// Ticker symbol of the asset.
+		pub symbol: Vec<u8>,
+		/// Number of decimals of the asset.
+		pub decimals: u8,
+	}
+
+	/// Automatic refill of a fungible asset from a treasury's allowance to the faucet.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct RefillConfig {
+		/// Account that approved the faucet to spend its units of the asset.
+		pub treasury: AccountId,
+		/// Faucet balance below which a refill is made.
+		pub threshold: Balance,
+		/// Units pulled from the treasury per refill.
+		pub chunk: Balance,
+		/// Units that can be pulled from the treasury per period.
+		pub cap: Balance,
+		/// Length of a period in blocks. Periods start at multiples of this value.
+		pub period: BlockNumber,
+	}
+
+	/// Accounting of the refills of a fungible asset.
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct RefillUsage {
+		/// Start block of the period `refilled` accounts for.
+		pub period_start: BlockNumber,
+		/// Units pulled from the treasury during the period.
+		pub refilled: Balance,
+	}
+
+	/// Ownership transfer awaiting acceptance by the new owner.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct PendingOwnership {
+		/// Account that can accept the ownership.
+		pub owner: AccountId,
+		/// Last block at which the ownership can be accepted, if any.
+		pub expires_at: Option<BlockNumber>,
+	}
+
+	/// Maximum amount of native tokens the faucet drips across all accounts per period.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct GlobalBudget {
+		/// Amount of tokens that can be dripped per period.
+		pub limit: Balance,
+		/// Length of a period in blocks. Periods start at multiples of this value.
+		pub period: BlockNumber,
+	}
+
+	/// Balance below which the faucet alerts that it is running low.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct LowWaterMark {
+		/// Balance below which `LowBalance` is emitted.
+		pub threshold: Balance,
+		/// Margin above `threshold` the balance must rise beyond before alerting again.
+		pub hysteresis: Balance,
+	}
+
+	/// How often an account can drip.
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub enum RateLimit {
+		/// Accounts wait `cooldown` blocks between drips.
+		#[default]
+		Cooldown,
+		/// Accounts accrue an allowance per block and can drip any amount up to it.
+		TokenBucket(TokenBucket),
+	}
+
+	/// How the amount dripped per request is derived from the faucet's balance.
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub enum DripCurve {
+		/// Always drip `drip_amount`.
+		#[default]
+		Fixed,
+		/// Drip `drip_amount` while the balance is at least `threshold`, tapering linearly
+		/// down to `min` as the balance approaches zero.
+		LinearTaper { threshold: Balance, min: Balance },
+		/// Drip `per_mill` thousandths of the balance, clamped between `min` and `max`.
+		Percentage { per_mill: u32, min: Balance, max: Balance },
+	}
+
+	/// Parameters of the token-bucket rate limit.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct TokenBucket {
+		/// Allowance accrued per block.
+		pub refill_per_block: Balance,
+		/// Maximum allowance an account can accrue. Accounts that never dripped start full.
+		pub burst: Balance,
+	}
+
+	/// Allowance of an account in token-bucket mode.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct BucketState {
+		/// Allowance left after the last drip.
+		pub tokens: Balance,
+		/// Block of the last drip.
+		pub updated_at: BlockNumber,
+	}
+
+	/// Change of the unit cooldowns are counted in.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct CooldownUnitChange {
+		/// Instant of the change, counted in the new unit.
+		pub at: Instant,
+		/// Instant of the change, counted in the previous unit.
+		pub previous_at: Instant,
+		/// Faucet's cooldown before the change, counted in the previous unit.
+		pub previous_cooldown: Duration,
+	}
+
+	/// Limits on the number of drips a single account can receive.
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct Quotas {
+		/// Maximum number of drips an account can ever receive, if any.
+		pub lifetime: Option<u32>,
+		/// Maximum number of drips an account can receive per window, if any.
+		pub window: Option<WindowQuota>,
+	}
+
+	/// Maximum number of drips per rolling window of blocks.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct WindowQuota {
+		/// Number of drips allowed in any window, at most `MAX_WINDOW_DRIPS`.
+		pub max_drips: u32,
+		/// Length of a window in blocks. The window always ends at the current block.
+		pub length: BlockNumber,
+	}
+
+	/// Drips received by an account, counted against the quotas.
+	#[derive(Clone, Debug, Default, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct QuotaUsage {
+		/// Number of drips ever received.
+		pub lifetime_drips: u32,
+		/// Blocks of the drips received in the current window, oldest first.
+		pub window_drips: Vec<BlockNumber>,
+	}
+
+	/// Drips an account can still receive under each quota, `None` if the quota is not set.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	pub struct RemainingQuota {
+		/// Drips left before reaching the lifetime quota.
+		pub lifetime: Option<u32>,
+		/// Drips left in the current window.
+		pub window: Option<u32>,
+	}
+
+	/// How long the faucet's balance is estimated to last.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	pub struct Runway {
+		/// Number of drips of the effective drip amount the balance still covers.
+		pub drips: Balance,
+		/// Number of blocks the balance lasts at the drip rate observed over the stats window,
+		/// or `None` if nothing has been dripped in the window.
+		pub blocks: Option<BlockNumber>,
+	}
+
+	/// Denylisting of an account.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct DenyEntry {
+		/// Application-defined code describing why the account is denied.
+		pub reason: u32,
+		/// Last block at which the account is denied, if any.
+		pub expires_at: Option<BlockNumber>,
+	}
+
+	/// Compressed ECDSA public key of an off-chain attester.
+	pub type AttesterKey = [u8; 33];
+
+	/// Authorization, signed by an attester, to drip `amount` tokens to `beneficiary`.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	pub struct Voucher {
+		/// Account receiving the tokens.
+		pub beneficiary: AccountId,
+		/// Amount of tokens to drip.
+		pub amount: Balance,
+		/// Last block at which the voucher can be redeemed.
+		pub expires_at: BlockNumber,
+		/// Attester-chosen number making the voucher unique.
+		pub nonce: u64,
+	}
+
 	/// Some tokens have been dripped.
 	#[ink(event)]
 	pub struct Drip {
 		value: Balance,
+		#[ink(topic)]
+		to: AccountId,
+	}
+
+	/// Some tokens have been dripped to a beneficiary on a sibling parachain.
+	#[ink(event)]
+	pub struct RemoteDrip {
+		value: Balance,
+		to: Location,
+	}
+
+	/// A role has been granted to an account.
+	#[ink(event)]
+	pub struct RoleGranted {
+		role: Role,
+		#[ink(topic)]
+		account: AccountId,
+		by: AccountId,
+	}
+
+	/// A role has been revoked from, or renounced by, an account.
+	#[ink(event)]
+	pub struct RoleRevoked {
+		role: Role,
+		#[ink(topic)]
+		account: AccountId,
+		by: AccountId,
+	}
+
+	/// An ownership transfer has been started and awaits acceptance.
+	#[ink(event)]
+	pub struct OwnershipTransferStarted {
+		#[ink(topic)]
+		from: AccountId,
+		#[ink(topic)]
+		to: AccountId,
+		expires_at: Option<BlockNumber>,
+	}
+
+	/// A pending ownership transfer has been cancelled.
+	#[ink(event)]
+	pub struct OwnershipTransferCancelled {
+		#[ink(topic)]
+		to: AccountId,
+	}
+
+	/// The ownership of the contract has been accepted by a new owner.
+	#[ink(event)]
+	pub struct OwnershipTransferred {
+		#[ink(topic)]
+		from: Option<AccountId>,
+		#[ink(topic)]
+		to: AccountId,
+	}
+
+	/// Funds have been sent to the faucet.
+	#[ink(event)]
+	pub struct Funded {
+		#[ink(topic)]
+		from: AccountId,
+		value: Balance,
+	}
+
+	/// The faucet's balance dropped below the low-water mark.
+	#[ink(event)]
+	pub struct LowBalance {
+		remaining: Balance,
+		threshold: Balance,
+	}
+
+	/// Funds have been withdrawn from the faucet.
+	#[ink(event)]
+	pub struct Withdrawn {
+		/// Withdrawn asset, or `None` for the native token.
+		asset_id: Option<TokenId>,
+		value: Balance,
+		#[ink(topic)]
+		to: AccountId,
+	}
+
+	/// Some units of a fungible asset have been dripped.
+	#[ink(event)]
+	pub struct AssetDrip {
+		#[ink(topic)]
+		asset_id: TokenId,
+		value: Balance,
+		#[ink(topic)]
 		to: AccountId,
 	}
 
+	/// The cooldown has been changed.
+	#[ink(event)]
+	pub struct CooldownChanged {
+		old: Duration,
+		new: Duration,
+	}
+
+	/// The unit cooldowns are counted in has been changed.
+	#[ink(event)]
+	pub struct CooldownUnitChanged {
+		old: CooldownUnit,
+		new: CooldownUnit,
+	}
+
+	/// Whether `drip_to` puts the caller in cooldown has been changed.
+	#[ink(event)]
+	pub struct CooldownCallerChanged {
+		old: bool,
+		new: bool,
+	}
+
+	/// The drip amount has been changed.
+	#[ink(event)]
+	pub struct DripAmountChanged {
+		old: Balance,
+		new: Balance,
+	}
+
+	/// The faucet has been activated or deactivated.
+	#[ink(event)]
+	pub struct ActivationChanged {
+		old: bool,
+		new: bool,
+	}
+
+	/// The owner has renounced the ownership of the contract.
+	#[ink(event)]
+	pub struct OwnershipRenounced {
+		#[ink(topic)]
+		previous_owner: AccountId,
+	}
+
+	/// The global drip budget has been changed.
+	#[ink(event)]
+	pub struct GlobalBudgetChanged {
+		old: Option<GlobalBudget>,
+		new: Option<GlobalBudget>,
+	}
+
+	/// The rate limit has been changed.
+	#[ink(event)]
+	pub struct RateLimitChanged {
+		old: RateLimit,
+		new: RateLimit,
+	}
+
+	/// The drip curve has been changed.
+	#[ink(event)]
+	pub struct DripCurveChanged {
+		old: DripCurve,
+		new: DripCurve,
+	}
+
+	/// The low-water mark has been changed.
+	#[ink(event)]
+	pub struct LowWaterMarkChanged {
+		old: Option<LowWaterMark>,
+		new: Option<LowWaterMark>,
+	}
+
+	/// The window the drip rate is observed over has been changed.
+	#[ink(event)]
+	pub struct StatsWindowChanged {
+		old: BlockNumber,
+		new: BlockNumber,
+	}
+
+	/// The maximum amount per `drip_amount_of` request has been changed.
+	#[ink(event)]
+	pub struct MaxRequestAmountChanged {
+		old: Balance,
+		new: Balance,
+	}
+
+	/// The per-account quotas have been changed.
+	#[ink(event)]
+	pub struct QuotasChanged {
+		old: Quotas,
+		new: Quotas,
+	}
+
+	/// The access mode has been changed.
+	#[ink(event)]
+	pub struct AccessModeChanged {
+		old: AccessMode,
+		new: AccessMode,
+	}
+
+	/// An account has been added to or removed from the allowlist.
+	#[ink(event)]
+	pub struct AllowlistChanged {
+		#[ink(topic)]
+		account: AccountId,
+		allowed: bool,
+	}
+
+	/// An account has been added to or removed from the denylist.
+	#[ink(event)]
+	pub struct DenylistChanged {
+		#[ink(topic)]
+		account: AccountId,
+		/// New denylisting of the account, or `None` if removed.
+		entry: Option<DenyEntry>,
+	}
+
+	/// An attester has been added or removed.
+	#[ink(event)]
+	pub struct AttesterChanged {
+		attester: AttesterKey,
+		allowed: bool,
+	}
+
+	/// The drip configuration of a fungible asset has been changed.
+	#[ink(event)]
+	pub struct AssetConfigChanged {
+		#[ink(topic)]
+		asset_id: TokenId,
+		/// Previous configuration, or `None` if the asset was not registered.
+		old: Option<AssetConfig>,
+		/// New configuration, or `None` if the asset has been unregistered.
+		new: Option<AssetConfig>,
+	}
+
+	/// The faucet pulled units of a fungible asset from its treasury.
+	#[ink(event)]
+	pub struct Refilled {
+		#[ink(topic)]
+		asset_id: TokenId,
+		#[ink(topic)]
+		from: AccountId,
+		value: Balance,
+	}
+
+	/// The minting configuration of a fungible asset has been changed.
+	#[ink(event)]
+	pub struct MintConfigChanged {
+		#[ink(topic)]
+		asset_id: TokenId,
+		/// Previous configuration, or `None` if the asset was transferred.
+		old: Option<MintConfig>,
+		/// New configuration, or `None` if the asset is now transferred.
+		new: Option<MintConfig>,
+	}
+
+	/// The refill configuration of a fungible asset has been changed.
+	#[ink(event)]
+	pub struct RefillConfigChanged {
+		#[ink(topic)]
+		asset_id: TokenId,
+		/// Previous configuration, or `None` if refills were disabled.
+		old: Option<RefillConfig>,
+		/// New configuration, or `None` if refills have been disabled.
+		new: Option<RefillConfig>,
+	}
+
 	#[ink(storage)]
 	pub struct Faucet {
 		// Whether this faucet is active.
 		active: bool,
-		// Number of blocks an account should wait between drip requests.
-		cooldown: BlockNumber,
+		// Number of blocks, or milliseconds, an account should wait between drip requests.
+		cooldown: Duration,
+		// Unit `cooldown` and per-asset cooldowns are counted in.
+		cooldown_unit: CooldownUnit,
+		// Last change of `cooldown_unit`, if any, against which requests recorded in the
+		// previous unit are checked.
+		cooldown_unit_change: Option<CooldownUnitChange>,
 		// Amount of tokens to drip per request.
 		drip_amount: Balance,
+		// How the amount dripped per request is derived from the faucet's balance.
+		drip_curve: DripCurve,
 		// Account owner of the contract. Set to the deployer at constructor.
 		owner: Option<AccountId>,
+		// Ownership transfer awaiting acceptance, if any.
+		pending_owner: Option<PendingOwnership>,
+		// Roles explicitly granted per account.
+		roles: Mapping<(Role, AccountId), ()>,
 		// Accounting of last request per account.
-		last_request_of: Mapping<AccountId, BlockNumber>,
+		last_request_of: Mapping<AccountId, Instant>,
+		// How often an account can drip.
+		rate_limit: RateLimit,
+		// Allowance per account in `RateLimit::TokenBucket` mode.
+		buckets: Mapping<AccountId, BucketState>,
+		// Per-account limits on the number of drips.
+		quotas: Quotas,
+		// Accounting of drips per account, counted against `quotas`.
+		quota_usage_of: Mapping<AccountId, QuotaUsage>,
+		// Amount of the last request per account, when it differs from `drip_amount`.
+		last_amount_of: Mapping<AccountId, Balance>,
+		// Maximum amount per `drip_amount_of` request.
+		max_request_amount: Balance,
+		// Whether `drip_to` also puts the caller in cooldown.
+		cooldown_caller: bool,
+		// Which accounts are eligible to drip.
+		access_mode: AccessMode,
+		// Accounts allowed to drip in `AccessMode::Allowlist`.
+		allowlist: Mapping<AccountId, ()>,
+		// Accounts denied from dripping in `AccessMode::Denylist` and `AccessMode::Allowlist`.
+		denylist: Mapping<AccountId, DenyEntry>,
+		// Public keys of the attesters allowed to sign vouchers.
+		attesters: Mapping<AttesterKey, ()>,
+		// Nonces of redeemed vouchers per attester.
+		used_vouchers: Mapping<(AttesterKey, u64), ()>,
+		// Global drip budget, if any.
+		global_budget: Option<GlobalBudget>,
+		// Start block of the period `budget_spent` accounts for.
+		budget_period_start: BlockNumber,
+		// Amount of tokens dripped during the budget period starting at `budget_period_start`.
+		budget_spent: Balance,
+		// Balance below which the faucet alerts that it is running low, if any.
+		low_water_mark: Option<LowWaterMark>,
+		// Whether `LowBalance` has been emitted since the balance last crossed the low-water
+		// mark.
+		low_balance_alerted: bool,
+		// Cumulative funds sent per donor, through the constructor or `fund`.
+		donations_of: Mapping<AccountId, Balance>,
+		// Largest donors by cumulative donations, in decreasing order. Kept lazy so that only
+		// funding and `top_donors` load it.
+		top_donors: Lazy<Vec<(AccountId, Balance)>>,
+		// Funds received through the constructor or `fund`.
+		total_received: Balance,
+		// Native tokens dripped.
+		total_dripped: Balance,
+		// Number of native drips.
+		drip_count: u64,
+		// Accounts that received at least one native drip.
+		recipients: Mapping<AccountId, ()>,
+		// Number of accounts in `recipients`.
+		recipient_count: u32,
+		// Units dripped per asset.
+		asset_dripped: Mapping<TokenId, Balance>,
+		// Length of the window the drip rate is observed over, in blocks.
+		stats_window: BlockNumber,
+		// Start block of the current stats window.
+		stats_window_start: BlockNumber,
+		// Native tokens dripped in the current stats window.
+		current_window_dripped: Balance,
+		// Native tokens dripped in the previous stats window.
+		previous_window_dripped: Balance,
+		// Fungible assets distributed by this faucet.
+		assets: Mapping<TokenId, AssetConfig>,
+		// Assets dripped by minting rather than transferring.
+		mint_configs: Mapping<TokenId, MintConfig>,
+		// Automatic refills per asset.
+		refills: Mapping<TokenId, RefillConfig>,
+		// Accounting of refills per asset, counted against their cap.
+		refill_usage_of: Mapping<TokenId, RefillUsage>,
+		// Accounting of last request per asset and account.
+		last_asset_request_of: Mapping<(TokenId, AccountId), Instant>,
+		// Accounting of last request per beneficiary on a sibling parachain.
+		last_remote_request_of: Mapping<Location, Instant>,
 	}
 
 	impl Faucet {
@@ -48,13 +772,104 @@ mod fungibles {
 		/// * - `drip_amount` - Amount of tokens to drip per `drip` call.
 		#[ink(constructor, payable)]
 		pub fn new(cooldown: BlockNumber, drip_amount: Balance) -> Self {
-			Self {
+			let cooldown = Duration::from(cooldown);
+			Self::new_with_cooldown_unit(cooldown, drip_amount, CooldownUnit::Blocks)
+		}
+
+		/// Instantiate the faucet with the given cooldown and drip amount, along with a new
+		/// fungible asset administered by the faucet and dripped by minting.
+		/// Deployer becomes the contract owner.
+		///
+		/// # Parameters
+		/// * - `cooldown` - Number of blocks an account should wait between drip requests.
+		/// * - `drip_amount` - Amount of tokens to drip per `drip` call.
+		/// * - `asset_id` - Identifier of the asset to create.
+		/// * - `min_balance` - Minimum balance of the asset an account can hold.
+		/// * - `asset_config` - Drip parameters of the asset.
+		/// * - `mint_config` - Minting parameters of the asset.
+		/// * - `metadata` - Metadata of the asset.
+		#[ink(constructor, payable)]
+		pub fn new_with_minted_asset(
+			cooldown: BlockNumber,
+			drip_amount: Balance,
+			asset_id: TokenId,
+			min_balance: Balance,
+			asset_config: AssetConfig,
+			mint_config: MintConfig,
+			metadata: AssetMetadata,
+		) -> Result<Self, FaucetError> {
+			let mut faucet = Self::new(cooldown, drip_amount);
+			api::create(asset_id, Self::env().account_id(), min_balance)?;
+			faucet.register_asset(asset_id, asset_config.drip_amount, asset_config.cooldown)?;
+			faucet.set_mint_config(asset_id, Some(mint_config))?;
+			faucet.set_asset_metadata(asset_id, metadata)?;
+			Ok(faucet)
+		}
+
+		/// Instantiate the faucet with the given cooldown, counted in `cooldown_unit`, and
+		/// drip amount.
+		/// Deployer becomes the contract owner.
+		///
+		/// # Parameters
+		/// * - `cooldown` - Number of `cooldown_unit` an account should wait between drip
+		///   requests.
+		/// * - `drip_amount` - Amount of tokens to drip per `drip` call.
+		/// * - `cooldown_unit` - Unit cooldowns are counted in.
+		#[ink(constructor, payable)]
+		pub fn new_with_cooldown_unit(
+			cooldown: Duration,
+			drip_amount: Balance,
+			cooldown_unit: CooldownUnit,
+		) -> Self {
+			let mut faucet = Self {
 				active: false,
 				cooldown,
+				cooldown_unit,
+				cooldown_unit_change: None,
 				drip_amount,
+				drip_curve: DripCurve::default(),
 				owner: Some(Self::env().caller()),
+				pending_owner: None,
+				roles: Mapping::default(),
 				last_request_of: Mapping::default(),
-			}
+				rate_limit: RateLimit::default(),
+				buckets: Mapping::default(),
+				quotas: Quotas::default(),
+				quota_usage_of: Mapping::default(),
+				last_amount_of: Mapping::default(),
+				max_request_amount: drip_amount,
+				cooldown_caller: false,
+				access_mode: AccessMode::default(),
+				allowlist: Mapping::default(),
+				denylist: Mapping::default(),
+				attesters: Mapping::default(),
+				used_vouchers: Mapping::default(),
+				global_budget: None,
+				budget_period_start: 0,
+				budget_spent: 0,
+				low_water_mark: None,
+				low_balance_alerted: false,
+				donations_of: Mapping::default(),
+				top_donors: Lazy::new(),
+				total_received: 0,
+				total_dripped: 0,
+				drip_count: 0,
+				recipients: Mapping::default(),
+				recipient_count: 0,
+				asset_dripped: Mapping::default(),
+				stats_window: DEFAULT_STATS_WINDOW,
+				stats_window_start: 0,
+				current_window_dripped: 0,
+				previous_window_dripped: 0,
+				assets: Mapping::default(),
+				mint_configs: Mapping::default(),
+				refills: Mapping::default(),
+				refill_usage_of: Mapping::default(),
+				last_asset_request_of: Mapping::default(),
+				last_remote_request_of: Mapping::default(),
+			};
+			faucet.record_funding(Self::env().caller(), Self::env().transferred_value());
+			faucet
 		}
 
 		/// Check if the faucet is active.
@@ -73,16 +888,271 @@ mod fungibles {
 			Ok(())
 		}
 
-		/// Check if caller can request a drip.
-		fn can_request(&self) -> Result<(), FaucetError> {
+		/// Check if the caller holds `role`.
+		fn ensure_role(&self, role: Role) -> Result<(), FaucetError> {
+			if !self.has_role(role, self.env().caller()) {
+				return Err(FaucetError::MissingRole);
+			}
+			Ok(())
+		}
+
+		/// Check if `account` is eligible to drip under the current access mode.
+		fn ensure_permitted(&self, account: AccountId) -> Result<(), FaucetError> {
+			if self.access_mode == AccessMode::Open {
+				return Ok(());
+			}
+			if self.access_mode == AccessMode::Allowlist && !self.allowlist.contains(account) {
+				return Err(FaucetError::NotAllowlisted);
+			}
+			if let Some(entry) = self.denylist.get(account) {
+				let expired = entry
+					.expires_at
+					.is_some_and(|expires_at| expires_at < self.env().block_number());
+				if !expired {
+					return Err(FaucetError::Denylisted {
+						reason: entry.reason,
+						expires_at: entry.expires_at,
+					});
+				}
+			}
+			Ok(())
+		}
+
+		/// Recover the attester of a voucher and check it can be redeemed.
+		fn verify_voucher(
+			&self,
+			voucher: &Voucher,
+			signature: &[u8; 65],
+		) -> Result<AttesterKey, FaucetError> {
+			if voucher.expires_at < self.env().block_number() {
+				return Err(FaucetError::VoucherExpired { expired_at: voucher.expires_at });
+			}
+			let attester = self
+				.env()
+				.ecdsa_recover(signature, &self.voucher_hash(*voucher))
+				.map_err(|_| FaucetError::InvalidSignature)?;
+			if !self.attesters.contains(attester) {
+				return Err(FaucetError::UnknownAttester);
+			}
+			if self.used_vouchers.contains((attester, voucher.nonce)) {
+				return Err(FaucetError::VoucherAlreadyUsed);
+			}
+			Ok(attester)
+		}
+
+		/// Run the checks a drip of `amount` tokens applies to `account`, in order.
+		fn check_eligibility(
+			&self,
+			account: AccountId,
+			amount: Balance,
+		) -> Result<(), FaucetError> {
+			self.ensure_active()?;
+			self.ensure_permitted(account)?;
+			self.can_withdraw(amount)?;
+			self.can_spend_budget(amount)?;
+			self.can_request(account, amount)?;
+			self.can_use_quota(account)
+		}
+
+		/// Drips of `account` counted against the quotas, without those that left the window.
+		fn current_quota_usage(&self, account: AccountId) -> QuotaUsage {
+			let mut usage = self.quota_usage_of.get(account).unwrap_or_default();
+			match self.quotas.window {
+				Some(window) => {
+					let current_block = self.env().block_number();
+					usage
+						.window_drips
+						.retain(|block| block.saturating_add(window.length) > current_block);
+				}
+				None => usage.window_drips.clear(),
+			}
+			usage
+		}
+
+		/// Check if `account` has not exhausted any of its quotas.
+		fn can_use_quota(&self, account: AccountId) -> Result<(), FaucetError> {
+			let usage = self.current_quota_usage(account);
+			if self.quotas.lifetime.is_some_and(|max_drips| usage.lifetime_drips >= max_drips) {
+				return Err(FaucetError::LifetimeQuotaExhausted);
+			}
+			if let Some(window) = self.quotas.window {
+				let window_drips = usage.window_drips.len();
+				let max_drips = window.max_drips as usize;
+				if window_drips >= max_drips {
+					// Another drip is allowed once enough drips left the window.
+					let available_at = usage
+						.window_drips
+						.get(window_drips - max_drips)
+						.map_or(BlockNumber::MAX, |block| block.saturating_add(window.length));
+					return Err(FaucetError::WindowQuotaExhausted { available_at });
+				}
+			}
+			Ok(())
+		}
+
+		/// Count a drip to `account` against its quotas.
+		fn use_quota(&mut self, account: AccountId) {
+			let mut usage = self.current_quota_usage(account);
+			usage.lifetime_drips = usage.lifetime_drips.saturating_add(1);
+			if self.quotas.window.is_some() {
+				usage.window_drips.push(self.env().block_number());
+			}
+			self.quota_usage_of.insert(account, &usage);
+		}
+
+		/// Check if `account` can request a drip of `amount` tokens under the rate limit.
+		fn can_request(&self, account: AccountId, amount: Balance) -> Result<(), FaucetError> {
+			match self.rate_limit {
+				RateLimit::Cooldown => {
+					let last_amount = self.last_amount_of.get(account).unwrap_or(self.drip_amount);
+					self.ensure_cooled_down(
+						self.last_request_of.try_get(account),
+						self.cooldown_for(last_amount),
+						self.previous_cooldown_for(last_amount),
+					)
+				}
+				RateLimit::TokenBucket(bucket) => {
+					let allowance = self.allowance_in(&bucket, account);
+					if amount > allowance {
+						return Err(FaucetError::AllowanceExceeded { allowance });
+					}
+					Ok(())
+				}
+			}
+		}
+
+		/// Register a drip of `amount` tokens requested by `account` under the rate limit.
+		fn register_request(
+			&mut self,
+			account: AccountId,
+			amount: Balance,
+		) -> Result<(), FaucetError> {
+			let current_block = self.env().block_number();
+			match self.rate_limit {
+				RateLimit::Cooldown => {
+					let full_amount = self.effective_drip_amount();
+					if amount == full_amount {
+						self.last_amount_of.remove(account);
+					} else {
+						// Record the amount relative to `drip_amount`, which cooldowns scale
+						// against, so that tapered drips are not followed by shorter cooldowns.
+						let amount = amount
+							.saturating_mul(self.drip_amount)
+							.checked_div(full_amount)
+							.unwrap_or(self.drip_amount);
+						self.last_amount_of.insert(account, &amount);
+					}
+				}
+				RateLimit::TokenBucket(bucket) => {
+					let tokens = self.allowance_in(&bucket, account).saturating_sub(amount);
+					self.buckets
+						.insert(account, &BucketState { tokens, updated_at: current_block });
+				}
+			}
+			self.last_request_of
+				.try_insert(account, &self.now())
+				.map_err(|_| FaucetError::ValueTooLarge)?;
+			Ok(())
+		}
+
+		/// Cooldown following a drip of `amount` tokens, proportional to its share of
+		/// `drip_amount` and rounded up.
+		fn cooldown_for(&self, amount: Balance) -> Duration {
+			self.scale_cooldown(self.cooldown, amount)
+		}
+
+		/// Cooldown that followed a drip of `amount` tokens before the last change of
+		/// `cooldown_unit`, counted in the previous unit.
+		fn previous_cooldown_for(&self, amount: Balance) -> Option<Duration> {
+			let change = self.cooldown_unit_change?;
+			Some(self.scale_cooldown(change.previous_cooldown, amount))
+		}
+
+		/// `cooldown` scaled to a drip of `amount` tokens.
+		fn scale_cooldown(&self, cooldown: Duration, amount: Balance) -> Duration {
+			if amount == self.drip_amount || self.drip_amount == 0 {
+				return cooldown;
+			}
+			let cooldown =
+				Balance::from(cooldown).saturating_mul(amount).div_ceil(self.drip_amount);
+			Duration::try_from(cooldown).unwrap_or(Duration::MAX)
+		}
+
+		/// Allowance `account` has accrued in `bucket` by the current block.
+		fn allowance_in(&self, bucket: &TokenBucket, account: AccountId) -> Balance {
+			let Some(state) = self.buckets.get(account) else {
+				return bucket.burst;
+			};
+			let elapsed = self.env().block_number().saturating_sub(state.updated_at);
+			let accrued = bucket.refill_per_block.saturating_mul(Balance::from(elapsed));
+			state.tokens.saturating_add(accrued).min(bucket.burst)
+		}
+
+		/// Check if caller can request a drip of `asset_id`.
+		fn can_request_asset(
+			&self,
+			asset_id: TokenId,
+			cooldown: Duration,
+		) -> Result<(), FaucetError> {
 			let caller = Self::env().caller();
-			let last_request_result = self.last_request_of.try_get(caller);
+			let last_request_result = self.last_asset_request_of.try_get((asset_id, caller));
+			self.ensure_cooled_down(last_request_result, cooldown, None)
+		}
+
+		/// Check if `beneficiary` on a sibling parachain can request a drip.
+		fn can_request_remote(&self, beneficiary: &Location) -> Result<(), FaucetError> {
+			self.ensure_cooled_down(
+				self.last_remote_request_of.try_get(beneficiary),
+				self.cooldown,
+				self.previous_cooldown_for(self.drip_amount),
+			)
+		}
+
+		/// Current instant, counted in `cooldown_unit`.
+		fn now(&self) -> Instant {
+			match self.cooldown_unit {
+				CooldownUnit::Blocks => Instant::Block(self.env().block_number()),
+				CooldownUnit::Milliseconds => Instant::Timestamp(self.env().block_timestamp()),
+			}
+		}
+
+		/// `instant` counted in `cooldown_unit`. Instants recorded in another unit are taken as
+		/// the moment the unit was changed, or dropped if `previous_cooldown`, their cooldown
+		/// counted in that unit, had already ended by then. Instants whose previous cooldown is
+		/// unknown are never dropped.
+		fn in_cooldown_unit(
+			&self,
+			instant: Instant,
+			previous_cooldown: Option<Duration>,
+		) -> Option<Instant> {
+			if instant.unit() == self.cooldown_unit {
+				return Some(instant);
+			}
+			let change = self.cooldown_unit_change?;
+			if previous_cooldown
+				.is_some_and(|cooldown| instant.saturating_add(cooldown) <= change.previous_at)
+			{
+				return None;
+			}
+			Some(change.at)
+		}
 
+		/// Check that `cooldown` has passed since the last request, if any. `previous_cooldown`
+		/// is the request's cooldown if it was recorded before the last change of unit.
+		fn ensure_cooled_down(
+			&self,
+			last_request_result: Option<ink::env::Result<Instant>>,
+			cooldown: Duration,
+			previous_cooldown: Option<Duration>,
+		) -> Result<(), FaucetError> {
 			match last_request_result {
 				Some(Ok(last_drip)) => {
-					let current_block = self.env().block_number();
-					if last_drip.saturating_add(self.cooldown) > current_block {
-						return Err(FaucetError::InCoolDown);
+					let Some(last_drip) = self.in_cooldown_unit(last_drip, previous_cooldown) else {
+						return Ok(());
+					};
+					let available_at = last_drip.saturating_add(cooldown);
+					if available_at > self.now() {
+						return Err(FaucetError::InCoolDown { available_at });
 					}
 				}
 				Some(Err(_)) => {
@@ -99,70 +1169,1068 @@ mod fungibles {
 			Ok(())
 		}
 
-		/// Check if faucet holds enough balance to drip.
-		fn can_withdraw(&self) -> Result<(), FaucetError> {
+		/// Check if faucet holds enough balance to transfer `amount` out.
+		fn can_withdraw(&self, amount: Balance) -> Result<(), FaucetError> {
+			let balance = self.env().balance();
 			// Don't let balance go lower than 1.
-			if self.drip_amount.saturating_add(1) >= self.env().balance() {
-				return Err(FaucetError::NotEnoughFunds);
+			if amount.saturating_add(1) >= balance {
+				return Err(FaucetError::NotEnoughFunds {
+					available: balance,
+					required: amount.saturating_add(2),
+				});
 			}
 			Ok(())
 		}
 
-		/// Faucet's cooldown.
-		#[ink(message)]
-		pub fn cooldown(&self) -> BlockNumber {
+		/// Start block of the budget period containing the current block.
+		fn current_budget_period_start(&self, budget: &GlobalBudget) -> BlockNumber {
+			let current_block = self.env().block_number();
+			current_block.saturating_sub(current_block.checked_rem(budget.period).unwrap_or(0))
+		}
+
+		/// Amount of tokens left to drip under `budget` during the current period.
+		fn remaining_budget_of(&self, budget: &GlobalBudget) -> Balance {
+			if self.budget_period_start != self.current_budget_period_start(budget) {
+				return budget.limit;
+			}
+			budget.limit.saturating_sub(self.budget_spent)
+		}
+
+		/// Check if `amount` tokens can be dripped without exceeding the global budget.
+		fn can_spend_budget(&self, amount: Balance) -> Result<(), FaucetError> {
+			if let Some(budget) = &self.global_budget {
+				let remaining = self.remaining_budget_of(budget);
+				if amount > remaining {
+					return Err(FaucetError::GlobalBudgetExhausted {
+						remaining,
+						resets_at: self
+							.current_budget_period_start(budget)
+							.saturating_add(budget.period),
+					});
+				}
+			}
+			Ok(())
+		}
+
+		/// Account `amount` dripped tokens against the global budget.
+		fn spend_budget(&mut self, amount: Balance) {
+			if let Some(budget) = self.global_budget {
+				let period_start = self.current_budget_period_start(&budget);
+				if self.budget_period_start != period_start {
+					self.budget_period_start = period_start;
+					self.budget_spent = 0;
+				}
+				self.budget_spent = self.budget_spent.saturating_add(amount);
+			}
+		}
+
+		/// Account `amount` native tokens dripped to `to`, or to a remote beneficiary if `None`.
+		fn record_drip(&mut self, to: Option<AccountId>, amount: Balance) {
+			self.total_dripped = self.total_dripped.saturating_add(amount);
+			self.drip_count = self.drip_count.saturating_add(1);
+			if let Some(to) = to {
+				if !self.recipients.contains(to) {
+					self.recipients.insert(to, &());
+					self.recipient_count = self.recipient_count.saturating_add(1);
+				}
+			}
+			let (window_start, previous, current) = self.current_stats_window();
+			self.stats_window_start = window_start;
+			self.previous_window_dripped = previous;
+			self.current_window_dripped = current.saturating_add(amount);
+		}
+
+		/// Start of the current stats window, with the amounts dripped in the previous and
+		/// current windows.
+		fn current_stats_window(&self) -> (BlockNumber, Balance, Balance) {
+			let current_block = self.env().block_number();
+			let window_start = current_block
+				.saturating_sub(current_block.checked_rem(self.stats_window).unwrap_or(0));
+			if window_start == self.stats_window_start {
+				(window_start, self.previous_window_dripped, self.current_window_dripped)
+			} else if window_start == self.stats_window_start.saturating_add(self.stats_window) {
+				(window_start, self.current_window_dripped, 0)
+			} else {
+				(window_start, 0, 0)
+			}
+		}
+
+		/// Estimate of the native tokens dripped over the last `stats_window` blocks, weighting
+		/// the previous window by its share still inside the sliding window.
+		fn dripped_in_stats_window(&self) -> Balance {
+			if self.stats_window == 0 {
+				return 0;
+			}
+			let (window_start, previous, current) = self.current_stats_window();
+			let elapsed = self.env().block_number().saturating_sub(window_start);
+			let overlap = Balance::from(self.stats_window.saturating_sub(elapsed));
+			let previous = previous.saturating_mul(overlap) / Balance::from(self.stats_window);
+			previous.saturating_add(current)
+		}
+
+		/// Emit `LowBalance` when the balance drops below the low-water mark, once until it rises
+		/// above the mark by its hysteresis again.
+		fn check_low_balance(&mut self) {
+			let Some(mark) = self.low_water_mark else {
+				return;
+			};
+			let remaining = self.env().balance();
+			if self.low_balance_alerted {
+				if remaining > mark.threshold.saturating_add(mark.hysteresis) {
+					self.low_balance_alerted = false;
+				}
+			} else if remaining < mark.threshold {
+				self.low_balance_alerted = true;
+				self.env().emit_event(LowBalance { remaining, threshold: mark.threshold });
+			}
+		}
+
+		/// Account `value` tokens sent by `from` to the faucet.
+		fn record_funding(&mut self, from: AccountId, value: Balance) {
+			if value == 0 {
+				return;
+			}
+			let donated = self.donations_of.get(from).unwrap_or(0).saturating_add(value);
+			self.donations_of.insert(from, &donated);
+			self.total_received = self.total_received.saturating_add(value);
+			// Keep the leaderboard sorted by decreasing donations.
+			let mut top_donors = self.top_donors.get().unwrap_or_default();
+			top_donors.retain(|(donor, _)| *donor != from);
+			let position = top_donors
+				.iter()
+				.position(|(_, donation)| *donation < donated)
+				.unwrap_or(top_donors.len());
+			if position < MAX_TOP_DONORS {
+				top_donors.insert(position, (from, donated));
+				top_donors.truncate(MAX_TOP_DONORS);
+			}
+			self.top_donors.set(&top_donors);
+			self.env().emit_event(Funded { from, value });
+		}
+
+		/// Transfer `amount` native tokens from the faucet to `to`.
+		///
+		/// Callers must record any state guarding against repeated requests before calling this,
+		/// as the recipient may be a contract that calls back into the faucet.
+		fn transfer_native(&self, to: AccountId, amount: Balance) -> Result<(), FaucetError> {
+			self.env().transfer(to, amount).map_err(|_| {
+				// Accounts without balance can only be endowed with at least the existential
+				// deposit.
+				if amount < self.env().minimum_balance() {
+					FaucetError::BelowExistentialDeposit
+				} else {
+					FaucetError::TransferFailed
+				}
+			})
+		}
+
+		/// Check if faucet holds enough units of `asset_id` to transfer `amount` out.
+		fn can_withdraw_asset(
+			&self,
+			asset_id: TokenId,
+			amount: Balance,
+		) -> Result<(), FaucetError> {
+			let balance = api::balance_of(asset_id, self.env().account_id())?;
+			// Don't let balance go lower than 1.
+			if amount.saturating_add(1) >= balance {
+				return Err(FaucetError::NotEnoughFunds {
+					available: balance,
+					required: amount.saturating_add(2),
+				});
+			}
+			Ok(())
+		}
+
+		/// Refill usage of `asset_id` for the current period of `config`.
+		fn current_refill_usage(&self, asset_id: TokenId, config: &RefillConfig) -> RefillUsage {
+			let current_block = self.env().block_number();
+			let period_start = current_block
+				.saturating_sub(current_block.checked_rem(config.period).unwrap_or(0));
+			match self.refill_usage_of.get(asset_id) {
+				Some(usage) if usage.period_start == period_start => usage,
+				_ => RefillUsage { period_start, refilled: 0 },
+			}
+		}
+
+		/// Pull a chunk of `asset_id` from its treasury if the faucet's balance is below the
+		/// refill threshold, within the cap of the current period.
+		fn refill_asset(&mut self, asset_id: TokenId) -> Result<(), FaucetError> {
+			let config = self.refills.get(asset_id).ok_or(FaucetError::RefillNotConfigured)?;
+			let faucet = self.env().account_id();
+			if api::balance_of(asset_id, faucet)? >= config.threshold {
+				return Ok(());
+			}
+			let mut usage = self.current_refill_usage(asset_id, &config);
+			let value = config.chunk.min(config.cap.saturating_sub(usage.refilled));
+			if value == 0 {
+				return Err(FaucetError::RefillCapExhausted {
+					resets_at: usage.period_start.saturating_add(config.period),
+				});
+			}
+			api::transfer_from(asset_id, config.treasury, faucet, value)?;
+			usage.refilled = usage.refilled.saturating_add(value);
+			self.refill_usage_of.insert(asset_id, &usage);
+			self.env().emit_event(Refilled { asset_id, from: config.treasury, value });
+			Ok(())
+		}
+
+		/// Check if minting `amount` units of `asset_id` stays within the supply cap of `mint`.
+		fn can_mint(
+			&self,
+			asset_id: TokenId,
+			mint: &MintConfig,
+			amount: Balance,
+		) -> Result<(), FaucetError> {
+			if let Some(cap) = mint.supply_cap {
+				if api::total_supply(asset_id)?.saturating_add(amount) > cap {
+					return Err(FaucetError::SupplyCapReached { cap });
+				}
+			}
+			Ok(())
+		}
+
+		/// Split a location of the form `../Parachain(para_id)/<account>` into the sibling
+		/// parachain id and the account junction, where the account is either an `AccountId32`
+		/// or an `AccountKey20`.
+		fn split_sibling_location(location: &Location) -> Result<(u32, Junction), FaucetError> {
+			if location.parent_count() != 1 {
+				return Err(FaucetError::InvalidLocation);
+			}
+			// Clear the network, so that equivalent locations of an account share a cooldown.
+			match location.interior().as_slice() {
+				[Junction::Parachain(para_id), Junction::AccountId32 { id, .. }] =>
+					Ok((*para_id, Junction::AccountId32 { network: None, id: *id })),
+				[Junction::Parachain(para_id), Junction::AccountKey20 { key, .. }] =>
+					Ok((*para_id, Junction::AccountKey20 { network: None, key: *key })),
+				_ => Err(FaucetError::InvalidLocation),
+			}
+		}
+
+		/// Location of `account` on sibling parachain `para_id`, in its canonical form.
+		fn sibling_location(para_id: u32, account: Junction) -> Location {
+			Location::new(1, [Junction::Parachain(para_id), account])
+		}
+
+		/// Build the XCM program moving `amount` native tokens to `beneficiary` on `para_id`.
+		///
+		/// The native token is reserve-backed by the relay chain, so the program withdraws it
+		/// here, moves it through the relay chain and deposits it on the sibling parachain.
+		/// Execution fees at each hop are paid from the transferred amount.
+		fn remote_drip_message(para_id: u32, beneficiary: Junction, amount: Balance) -> Xcm<()> {
+			let all = || AssetFilter::Wild(WildAsset::All);
+			// Fees paid at each hop leave less than `amount` in the holding register, so execution
+			// is bought with a share of it. Unused fees are deposited to the beneficiary.
+			let fees = amount / 2;
+			Xcm(vec![
+				Instruction::WithdrawAsset(Asset::from((Parent, amount)).into()),
+				Instruction::InitiateReserveWithdraw {
+					assets: all(),
+					reserve: Parent.into(),
+					xcm: Xcm(vec![
+						Instruction::BuyExecution {
+							fees: (Junctions::Here, fees).into(),
+							weight_limit: WeightLimit::Unlimited,
+						},
+						Instruction::DepositReserveAsset {
+							assets: all(),
+							dest: Location::new(0, [Junction::Parachain(para_id)]),
+							xcm: Xcm(vec![
+								Instruction::BuyExecution {
+									fees: (Parent, fees).into(),
+									weight_limit: WeightLimit::Unlimited,
+								},
+								Instruction::DepositAsset {
+									assets: all(),
+									beneficiary: Location::new(0, [beneficiary]),
+								},
+							]),
+						},
+					]),
+				},
+			])
+		}
+
+		/// Drip configuration of `asset_id`, if registered.
+		fn asset(&self, asset_id: TokenId) -> Result<AssetConfig, FaucetError> {
+			self.assets.get(asset_id).ok_or(FaucetError::AssetNotRegistered)
+		}
+
+		/// Faucet's cooldown, counted in `cooldown_unit`.
+		#[ink(message)]
+		pub fn cooldown(&self) -> Duration {
 			self.cooldown
 		}
 
+		/// Unit cooldowns are counted in.
+		#[ink(message)]
+		pub fn cooldown_unit(&self) -> CooldownUnit {
+			self.cooldown_unit
+		}
+
 		/// Faucet's drip amount.
 		#[ink(message)]
-		pub fn drip_amount(&self) -> Balance {
-			self.drip_amount
+		pub fn drip_amount(&self) -> Balance {
+			self.drip_amount
+		}
+
+		/// Faucet's drip curve.
+		#[ink(message)]
+		pub fn drip_curve(&self) -> DripCurve {
+			self.drip_curve
+		}
+
+		/// Amount dripped per request given the faucet's current balance and drip curve.
+		#[ink(message)]
+		pub fn effective_drip_amount(&self) -> Balance {
+			let balance = self.env().balance();
+			match self.drip_curve {
+				DripCurve::Fixed => self.drip_amount,
+				DripCurve::LinearTaper { threshold, min } => {
+					if balance >= threshold {
+						return self.drip_amount;
+					}
+					let taper = self.drip_amount.saturating_sub(min).saturating_mul(balance) /
+						threshold;
+					min.saturating_add(taper)
+				}
+				DripCurve::Percentage { per_mill, min, max } => {
+					let amount = balance.saturating_mul(Balance::from(per_mill)) / 1000;
+					amount.max(min).min(max)
+				}
+			}
+		}
+
+		/// Whether `drip_to` also puts the caller in cooldown.
+		#[ink(message)]
+		pub fn cooldown_caller(&self) -> bool {
+			self.cooldown_caller
+		}
+
+		/// Whether Faucet is active or not.
+		#[ink(message)]
+		pub fn is_active(&self) -> bool {
+			self.active
+		}
+
+		/// Account's last drip instant, counted in `cooldown_unit`.
+		///
+		/// # Parameters
+		/// - `account` - Account to check.
+		#[ink(message)]
+		pub fn last_request_of(&self, account: AccountId) -> Option<Instant> {
+			let last_amount = self.last_amount_of.get(account).unwrap_or(self.drip_amount);
+			let previous_cooldown = self.previous_cooldown_for(last_amount);
+			self.last_request_of
+				.get(account)
+				.and_then(|instant| self.in_cooldown_unit(instant, previous_cooldown))
+		}
+
+		/// Maximum amount per `drip_amount_of` request.
+		#[ink(message)]
+		pub fn max_request_amount(&self) -> Balance {
+			self.max_request_amount
+		}
+
+		/// Faucet's rate limit.
+		#[ink(message)]
+		pub fn rate_limit(&self) -> RateLimit {
+			self.rate_limit
+		}
+
+		/// Allowance `account` has accrued, if the faucet is in token-bucket mode.
+		///
+		/// # Parameters
+		/// - `account` - Account to check.
+		#[ink(message)]
+		pub fn allowance_of(&self, account: AccountId) -> Option<Balance> {
+			match &self.rate_limit {
+				RateLimit::Cooldown => None,
+				RateLimit::TokenBucket(bucket) => Some(self.allowance_in(bucket, account)),
+			}
+		}
+
+		/// Faucet's per-account quotas.
+		#[ink(message)]
+		pub fn quotas(&self) -> Quotas {
+			self.quotas
+		}
+
+		/// Drips of `account` counted against the quotas.
+		///
+		/// # Parameters
+		/// - `account` - Account to check.
+		#[ink(message)]
+		pub fn quota_usage_of(&self, account: AccountId) -> QuotaUsage {
+			self.current_quota_usage(account)
+		}
+
+		/// Drips `account` can still receive under each quota.
+		///
+		/// # Parameters
+		/// - `account` - Account to check.
+		#[ink(message)]
+		pub fn remaining_quota(&self, account: AccountId) -> RemainingQuota {
+			let usage = self.current_quota_usage(account);
+			RemainingQuota {
+				lifetime: self
+					.quotas
+					.lifetime
+					.map(|max_drips| max_drips.saturating_sub(usage.lifetime_drips)),
+				window: self
+					.quotas
+					.window
+					.map(|window| {
+						window.max_drips.saturating_sub(usage.window_drips.len() as u32)
+					}),
+			}
+		}
+
+		/// Whether `account` could drip right now, or the first check it fails.
+		///
+		/// # Parameters
+		/// - `account` - Account to check.
+		#[ink(message)]
+		pub fn eligibility(&self, account: AccountId) -> Result<(), FaucetError> {
+			self.check_eligibility(account, self.effective_drip_amount())
+		}
+
+		/// Hash an attester has to sign for `voucher` to be redeemable on this faucet.
+		///
+		/// # Parameters
+		/// - `voucher` - Voucher to hash.
+		#[ink(message)]
+		pub fn voucher_hash(&self, voucher: Voucher) -> [u8; 32] {
+			// Bind the voucher to this contract so it cannot be redeemed on another faucet.
+			self.env().hash_encoded::<Blake2x256, _>(&(self.env().account_id(), voucher))
+		}
+
+		/// Whether `attester` can sign vouchers.
+		///
+		/// # Parameters
+		/// - `attester` - Compressed ECDSA public key of the attester.
+		#[ink(message)]
+		pub fn is_attester(&self, attester: AttesterKey) -> bool {
+			self.attesters.contains(attester)
+		}
+
+		/// Faucet's access mode.
+		#[ink(message)]
+		pub fn access_mode(&self) -> AccessMode {
+			self.access_mode
+		}
+
+		/// Whether `account` is allowlisted.
+		///
+		/// # Parameters
+		/// - `account` - Account to check.
+		#[ink(message)]
+		pub fn is_allowlisted(&self, account: AccountId) -> bool {
+			self.allowlist.contains(account)
+		}
+
+		/// Denylisting of `account`, if any.
+		///
+		/// # Parameters
+		/// - `account` - Account to check.
+		#[ink(message)]
+		pub fn denylist_entry(&self, account: AccountId) -> Option<DenyEntry> {
+			self.denylist.get(account)
+		}
+
+		/// Balance below which the faucet alerts that it is running low, if any.
+		#[ink(message)]
+		pub fn low_water_mark(&self) -> Option<LowWaterMark> {
+			self.low_water_mark
+		}
+
+		/// Faucet's global drip budget, if any.
+		#[ink(message)]
+		pub fn global_budget(&self) -> Option<GlobalBudget> {
+			self.global_budget
+		}
+
+		/// Amount of tokens left to drip in the current budget period, if there is a global
+		/// budget.
+		#[ink(message)]
+		pub fn remaining_budget(&self) -> Option<Balance> {
+			self.global_budget.as_ref().map(|budget| self.remaining_budget_of(budget))
+		}
+
+		/// Funds received through the constructor or `fund`.
+		#[ink(message)]
+		pub fn total_received(&self) -> Balance {
+			self.total_received
+		}
+
+		/// Native tokens dripped, including vouchers and cross-chain drips.
+		#[ink(message)]
+		pub fn total_dripped(&self) -> Balance {
+			self.total_dripped
+		}
+
+		/// Number of native drips, including vouchers and cross-chain drips.
+		#[ink(message)]
+		pub fn drip_count(&self) -> u64 {
+			self.drip_count
+		}
+
+		/// Number of distinct accounts that received a native drip on this chain.
+		#[ink(message)]
+		pub fn unique_recipients(&self) -> u32 {
+			self.recipient_count
+		}
+
+		/// Units of `asset_id` dripped.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset.
+		#[ink(message)]
+		pub fn asset_total_dripped(&self, asset_id: TokenId) -> Balance {
+			self.asset_dripped.get(asset_id).unwrap_or(0)
+		}
+
+		/// Length of the window the drip rate is observed over, in blocks.
+		#[ink(message)]
+		pub fn stats_window(&self) -> BlockNumber {
+			self.stats_window
+		}
+
+		/// How long the faucet's balance is estimated to last, in drips of the effective drip
+		/// amount and in blocks at the drip rate observed over the last `stats_window` blocks.
+		#[ink(message)]
+		pub fn runway(&self) -> Runway {
+			// Don't count the unit the faucet keeps to stay alive.
+			let balance = self.env().balance().saturating_sub(1);
+			let drips = balance.checked_div(self.effective_drip_amount()).unwrap_or(Balance::MAX);
+			let blocks = balance
+				.saturating_mul(Balance::from(self.stats_window))
+				.checked_div(self.dripped_in_stats_window())
+				.map(|blocks| BlockNumber::try_from(blocks).unwrap_or(BlockNumber::MAX));
+			Runway { drips, blocks }
+		}
+
+		/// Cumulative funds sent by `account` through the constructor or `fund`.
+		///
+		/// # Parameters
+		/// - `account` - Donor to check.
+		#[ink(message)]
+		pub fn donations_of(&self, account: AccountId) -> Balance {
+			self.donations_of.get(account).unwrap_or(0)
+		}
+
+		/// Largest donors with their cumulative donations, in decreasing order.
+		#[ink(message)]
+		pub fn top_donors(&self) -> Vec<(AccountId, Balance)> {
+			self.top_donors.get().unwrap_or_default()
+		}
+
+		/// Drip configuration of a registered asset.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset.
+		#[ink(message)]
+		pub fn asset_config(&self, asset_id: TokenId) -> Option<AssetConfig> {
+			self.assets.get(asset_id)
+		}
+
+		/// Minting configuration of a registered asset, if it is dripped by minting.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset.
+		#[ink(message)]
+		pub fn mint_config(&self, asset_id: TokenId) -> Option<MintConfig> {
+			self.mint_configs.get(asset_id)
+		}
+
+		/// Refill configuration of a registered asset, if refills are enabled.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset.
+		#[ink(message)]
+		pub fn refill_config(&self, asset_id: TokenId) -> Option<RefillConfig> {
+			self.refills.get(asset_id)
+		}
+
+		/// Units of `asset_id` that can still be pulled from its treasury in the current
+		/// period, if refills are enabled.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset.
+		#[ink(message)]
+		pub fn remaining_refill(&self, asset_id: TokenId) -> Option<Balance> {
+			let config = self.refills.get(asset_id)?;
+			let usage = self.current_refill_usage(asset_id, &config);
+			Some(config.cap.saturating_sub(usage.refilled))
+		}
+
+		/// Account's last drip instant of `asset_id`, counted in `cooldown_unit`.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset.
+		/// - `account` - Account to check.
+		#[ink(message)]
+		pub fn last_asset_request_of(
+			&self,
+			asset_id: TokenId,
+			account: AccountId,
+		) -> Option<Instant> {
+			self.last_asset_request_of
+				.get((asset_id, account))
+				.and_then(|instant| self.in_cooldown_unit(instant, None))
+		}
+
+		/// Last drip instant of a beneficiary on a sibling parachain, counted in
+		/// `cooldown_unit`.
+		///
+		/// # Parameters
+		/// - `location` - Beneficiary location relative to this chain.
+		#[ink(message)]
+		pub fn last_remote_request_of(&self, location: VersionedLocation) -> Option<Instant> {
+			let location = Location::try_from(location).ok()?;
+			let (para_id, account) = Self::split_sibling_location(&location).ok()?;
+			let previous_cooldown = self.previous_cooldown_for(self.drip_amount);
+			self.last_remote_request_of
+				.get(Self::sibling_location(para_id, account))
+				.and_then(|instant| self.in_cooldown_unit(instant, previous_cooldown))
+		}
+
+		/// Faucet owner account, if there is one.
+		#[ink(message)]
+		pub fn owner(&self) -> Option<AccountId> {
+			self.owner
+		}
+
+		/// Ownership transfer awaiting acceptance, if there is one.
+		#[ink(message)]
+		pub fn pending_owner(&self) -> Option<PendingOwnership> {
+			self.pending_owner
+		}
+
+		/// Whether `account` holds `role`, either explicitly, through `Role::Admin` or by being
+		/// the owner.
+		///
+		/// # Parameters
+		/// - `role` - Role to check.
+		/// - `account` - Account to check.
+		#[ink(message)]
+		pub fn has_role(&self, role: Role, account: AccountId) -> bool {
+			self.owner == Some(account)
+				|| self.roles.contains((role, account))
+				|| self.roles.contains((Role::Admin, account))
+		}
+
+		/// Transfer `amount` tokens to `beneficiary`, applying the rate limit to the
+		/// beneficiary and, if `cooldown_caller` is set, to the caller as well.
+		fn drip_native(
+			&mut self,
+			beneficiary: AccountId,
+			amount: Balance,
+		) -> Result<(), FaucetError> {
+			if amount == 0 {
+				return Err(FaucetError::ZeroAmount);
+			}
+			let caller = self.env().caller();
+			let track_caller = self.cooldown_caller && caller != beneficiary;
+
+			self.check_eligibility(beneficiary, amount)?;
+			if track_caller {
+				self.can_request(caller, amount)?;
+			}
+
+			// Register drip for beneficiary and, if tracked, caller.
+			self.register_request(beneficiary, amount)?;
+			if track_caller {
+				self.register_request(caller, amount)?;
+			}
+			self.use_quota(beneficiary);
+			self.spend_budget(amount);
+			self.record_drip(Some(beneficiary), amount);
+			// Do drip.
+			self.transfer_native(beneficiary, amount)?;
+			// Notify.
+			self.env().emit_event(
+				Drip {
+					value: amount,
+					to: beneficiary,
+				}
+			);
+			self.check_low_balance();
+			Ok(())
+		}
+
+		/// Transfer the effective drip amount to the caller.
+		/// if:
+		/// - effective drip amount is not zero,
+		/// - faucet is active,
+		/// - caller is eligible under the access mode,
+		/// - caller is not in cooldown,
+		/// - caller has not exhausted its quotas,
+		/// - faucet holds enough funds,
+		/// - global budget of the current period is not exhausted.
+		#[ink(message)]
+		pub fn drip(&mut self) -> Result<(), FaucetError> {
+			self.drip_native(self.env().caller(), self.effective_drip_amount())
+		}
+
+		/// Transfer `amount` tokens to the caller.
+		/// In cooldown mode, the cooldown that follows is scaled by `amount` over the effective
+		/// drip amount, so smaller requests come back sooner. In token-bucket mode, `amount` is
+		/// drawn from the caller's allowance.
+		/// if:
+		/// - `amount` is not zero nor above `max_request_amount` or the effective drip amount,
+		/// - faucet is active,
+		/// - caller is eligible under the access mode,
+		/// - caller is not in cooldown, or has accrued at least `amount` allowance,
+		/// - caller has not exhausted its quotas,
+		/// - faucet holds enough funds,
+		/// - global budget of the current period is not exhausted.
+		///
+		/// # Parameters
+		/// - `amount` - Amount of tokens to drip.
+		#[ink(message)]
+		pub fn drip_amount_of(&mut self, amount: Balance) -> Result<(), FaucetError> {
+			let maximum = self.max_request_amount.min(self.effective_drip_amount());
+			if amount > maximum {
+				return Err(FaucetError::AmountAboveMaximum { maximum });
+			}
+			self.drip_native(self.env().caller(), amount)
+		}
+
+		/// Transfer the effective drip amount to another account, with the caller paying the fees.
+		/// if:
+		/// - effective drip amount is not zero,
+		/// - faucet is active,
+		/// - beneficiary is eligible under the access mode,
+		/// - beneficiary is not in cooldown,
+		/// - caller is not in cooldown, when `cooldown_caller` is set,
+		/// - beneficiary has not exhausted its quotas,
+		/// - faucet holds enough funds,
+		/// - global budget of the current period is not exhausted.
+		///
+		/// # Parameters
+		/// - `beneficiary` - Account receiving the tokens.
+		#[ink(message)]
+		pub fn drip_to(&mut self, beneficiary: AccountId) -> Result<(), FaucetError> {
+			self.drip_native(beneficiary, self.effective_drip_amount())
+		}
+
+		/// Transfer the amount of a voucher signed by an attester to its beneficiary.
+		/// if:
+		/// - faucet is active,
+		/// - voucher has not expired and is signed by a registered attester,
+		/// - voucher has not been redeemed yet,
+		/// - beneficiary is eligible under the access mode,
+		/// - faucet holds enough funds,
+		/// - global budget of the current period is not exhausted.
+		///
+		/// The beneficiary's cooldown is not checked, but is restarted.
+		///
+		/// # Parameters
+		/// - `voucher` - Voucher to redeem.
+		/// - `signature` - ECDSA signature of `voucher_hash(voucher)` by an attester.
+		#[ink(message)]
+		pub fn drip_with_voucher(
+			&mut self,
+			voucher: Voucher,
+			signature: [u8; 65],
+		) -> Result<(), FaucetError> {
+			self.ensure_active()?;
+			let attester = self.verify_voucher(&voucher, &signature)?;
+			self.ensure_permitted(voucher.beneficiary)?;
+			self.can_withdraw(voucher.amount)?;
+			self.can_spend_budget(voucher.amount)?;
+
+			// Consume voucher.
+			self.used_vouchers.insert((attester, voucher.nonce), &());
+			// Register drip instant for beneficiary.
+			self.last_request_of
+				.try_insert(voucher.beneficiary, &self.now())
+				.map_err(|_| FaucetError::ValueTooLarge)?;
+			// Vouchers are followed by the full cooldown, whatever the amount of the last drip.
+			self.last_amount_of.remove(voucher.beneficiary);
+			self.spend_budget(voucher.amount);
+			self.record_drip(Some(voucher.beneficiary), voucher.amount);
+			// Do drip.
+			self.transfer_native(voucher.beneficiary, voucher.amount)?;
+			// Notify.
+			self.env().emit_event(
+				Drip {
+					value: voucher.amount,
+					to: voucher.beneficiary,
+				}
+			);
+			self.check_low_balance();
+			Ok(())
+		}
+
+		/// Transfer the effective drip amount to a beneficiary on a sibling parachain.
+		/// if:
+		/// - effective drip amount is not zero,
+		/// - faucet is active,
+		/// - caller is eligible under the access mode,
+		/// - beneficiary is not in cooldown,
+		/// - faucet holds enough funds,
+		/// - global budget of the current period is not exhausted.
+		///
+		/// Execution fees on the relay and destination chains are deducted from the dripped
+		/// amount.
+		///
+		/// # Parameters
+		/// - `location` - Beneficiary location relative to this chain, of the form
+		///   `../Parachain(para_id)/AccountId32(..)` or `../Parachain(para_id)/AccountKey20(..)`.
+		#[ink(message)]
+		pub fn drip_to_location(&mut self, location: VersionedLocation) -> Result<(), FaucetError> {
+			let location = Location::try_from(location).map_err(|_| FaucetError::InvalidLocation)?;
+			let (para_id, beneficiary) = Self::split_sibling_location(&location)?;
+			let location = Self::sibling_location(para_id, beneficiary);
+
+			let amount = self.effective_drip_amount();
+			if amount == 0 {
+				return Err(FaucetError::ZeroAmount);
+			}
+			self.ensure_active()?;
+			self.ensure_permitted(self.env().caller())?;
+			self.can_withdraw(amount)?;
+			self.can_spend_budget(amount)?;
+			self.can_request_remote(&location)?;
+
+			// Register drip instant for beneficiary.
+			self.last_remote_request_of
+				.try_insert(&location, &self.now())
+				.map_err(|_| FaucetError::ValueTooLarge)?;
+			self.spend_budget(amount);
+			self.record_drip(None, amount);
+			// Do drip.
+			let message = Self::remote_drip_message(para_id, beneficiary, amount);
+			self.env()
+				.xcm_execute(&VersionedXcm::V4(message))
+				.map_err(|_| FaucetError::XcmExecutionFailed)?;
+			// Notify.
+			self.env().emit_event(
+				RemoteDrip {
+					value: amount,
+					to: location,
+				}
+			);
+			self.check_low_balance();
+			Ok(())
+		}
+
+		/// Transfer the registered drip amount of `asset_id` to the caller.
+		/// if:
+		/// - faucet is active,
+		/// - caller is eligible under the access mode,
+		/// - asset is registered,
+		/// - caller is not in cooldown for this asset,
+		/// - faucet holds enough units of the asset, or minting them stays within the supply
+		///   cap for minted assets.
+		///
+		/// Minted assets are minted to the caller instead. Otherwise, if refills are enabled for
+		/// the asset and the faucet's balance is below the refill threshold, a refill is
+		/// attempted first.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset to drip.
+		#[ink(message)]
+		pub fn drip_asset(&mut self, asset_id: TokenId) -> Result<(), FaucetError> {
+			self.ensure_active()?;
+			self.ensure_permitted(self.env().caller())?;
+			let config = self.asset(asset_id)?;
+			let mint = self.mint_configs.get(asset_id);
+			match &mint {
+				Some(mint) => self.can_mint(asset_id, mint, config.drip_amount)?,
+				None => {
+					if self.refills.contains(asset_id) {
+						// Best effort: a failed refill must not prevent dripping what is left.
+						let _ = self.refill_asset(asset_id);
+					}
+					self.can_withdraw_asset(asset_id, config.drip_amount)?;
+				}
+			}
+			self.can_request_asset(asset_id, config.cooldown)?;
+
+			let caller = self.env().caller();
+
+			// Register drip instant for caller.
+			self.last_asset_request_of
+				.try_insert((asset_id, caller), &self.now())
+				.map_err(|_| FaucetError::ValueTooLarge)?;
+			let dripped = self.asset_dripped.get(asset_id).unwrap_or(0);
+			self.asset_dripped.insert(asset_id, &dripped.saturating_add(config.drip_amount));
+			// Do drip.
+			match mint {
+				Some(_) => api::mint(asset_id, caller, config.drip_amount)?,
+				None => api::transfer(asset_id, caller, config.drip_amount)?,
+			}
+			// Notify.
+			self.env().emit_event(
+				AssetDrip {
+					asset_id,
+					value: config.drip_amount,
+					to: caller,
+				}
+			);
+			Ok(())
+		}
+
+		/// Register a fungible asset to be distributed by the faucet, or update its configuration.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset.
+		/// - `drip_amount` - Amount of the asset to drip per `drip_asset` call.
+		/// - `cooldown` - Number of `cooldown_unit` an account should wait between requests of
+		///   this asset.
+		#[ink(message)]
+		pub fn register_asset(
+			&mut self,
+			asset_id: TokenId,
+			drip_amount: Balance,
+			cooldown: Duration,
+		) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			let config = AssetConfig { drip_amount, cooldown };
+			let old = self.assets.get(asset_id);
+			self.assets.insert(asset_id, &config);
+			self.env().emit_event(
+				AssetConfigChanged {
+					asset_id,
+					old,
+					new: Some(config),
+				}
+			);
+			Ok(())
 		}
 
-		/// Whether Faucet is active or not.
+		/// Stop distributing a fungible asset.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset.
 		#[ink(message)]
-		pub fn is_active(&self) -> bool {
-			self.active
+		pub fn unregister_asset(&mut self, asset_id: TokenId) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			let old = self.asset(asset_id)?;
+			self.assets.remove(asset_id);
+			if let Some(mint) = self.mint_configs.take(asset_id) {
+				self.env().emit_event(
+					MintConfigChanged {
+						asset_id,
+						old: Some(mint),
+						new: None,
+					}
+				);
+			}
+			if let Some(refill) = self.refills.take(asset_id) {
+				self.env().emit_event(
+					RefillConfigChanged {
+						asset_id,
+						old: Some(refill),
+						new: None,
+					}
+				);
+			}
+			self.env().emit_event(
+				AssetConfigChanged {
+					asset_id,
+					old: Some(old),
+					new: None,
+				}
+			);
+			Ok(())
 		}
 
-		/// Caller's last drip block number.
+		/// Drip a registered asset by minting it, or stop doing so. The faucet has to be the
+		/// issuer of the asset, e.g. because it created it or its admin made it so.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset.
+		/// - `mint_config` - New minting configuration, or `None` to transfer the asset from
+		///   the faucet's balance instead.
 		#[ink(message)]
-		pub fn last_request_of(&self) -> Option<BlockNumber> {
-			self.last_request_of.get(self.env().caller())
+		pub fn set_mint_config(
+			&mut self,
+			asset_id: TokenId,
+			mint_config: Option<MintConfig>,
+		) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			self.asset(asset_id)?;
+			let old = self.mint_configs.get(asset_id);
+			match mint_config {
+				Some(config) => {
+					self.mint_configs.insert(asset_id, &config);
+				}
+				None => self.mint_configs.remove(asset_id),
+			}
+			self.env().emit_event(
+				MintConfigChanged {
+					asset_id,
+					old,
+					new: mint_config,
+				}
+			);
+			Ok(())
 		}
 
-		/// Faucet owner account, if there is one.
+		/// Set the metadata of a registered asset. The faucet has to be the owner of the asset.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset.
+		/// - `metadata` - New metadata.
 		#[ink(message)]
-		pub fn owner(&self) -> Option<AccountId> {
-			self.owner
+		pub fn set_asset_metadata(
+			&mut self,
+			asset_id: TokenId,
+			metadata: AssetMetadata,
+		) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			self.asset(asset_id)?;
+			api::set_metadata(asset_id, metadata.name, metadata.symbol, metadata.decimals)?;
+			Ok(())
 		}
 
-		/// Transfer drip_amount tokens to the caller.
+		/// Pull a chunk of `asset_id` from its treasury if the faucet's balance is below the
+		/// refill threshold. Does nothing otherwise. Anyone can call this message.
 		/// if:
-		/// - faucet is active,
-		/// - caller is not in cooldown,
-		/// - faucet holds enough funds.
+		/// - asset is registered and refills are enabled for it,
+		/// - refills of the current period have not reached their cap,
+		/// - treasury's allowance to the faucet and balance cover the chunk.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset to refill.
 		#[ink(message)]
-		pub fn drip(&mut self) -> Result<(), FaucetError> {
-			self.ensure_active()?;
-			self.can_withdraw()?;
-			self.can_request()?;
-
-			let caller = self.env().caller();
+		pub fn refill(&mut self, asset_id: TokenId) -> Result<(), FaucetError> {
+			self.asset(asset_id)?;
+			self.refill_asset(asset_id)
+		}
 
-			// Do drip.
-			self.env()
-				.transfer(caller, self.drip_amount).expect("Some tokens have been transferred");
-			// Register drip block# for caller.
-			self.last_request_of
-				.try_insert(caller, &self.env().block_number())
-				.map_err(|_| FaucetError::ValueTooLarge)?;
-			// Notify.
+		/// Enable or disable automatic refills of a registered asset. The treasury has to
+		/// approve the faucet to spend its units of the asset beforehand.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset.
+		/// - `refill` - New refill configuration, or `None` to disable refills.
+		#[ink(message)]
+		pub fn set_refill(
+			&mut self,
+			asset_id: TokenId,
+			refill: Option<RefillConfig>,
+		) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			self.asset(asset_id)?;
+			if refill.is_some_and(|config| config.period == 0) {
+				return Err(FaucetError::InvalidPeriod);
+			}
+			let old = self.refills.get(asset_id);
+			match refill {
+				Some(config) => {
+					self.refills.insert(asset_id, &config);
+				}
+				None => self.refills.remove(asset_id),
+			}
 			self.env().emit_event(
-				Drip {
-					value: self.drip_amount,
-					to: self.env().caller(),
+				RefillConfigChanged {
+					asset_id,
+					old,
+					new: refill,
 				}
 			);
 			Ok(())
@@ -171,11 +2239,284 @@ mod fungibles {
 		/// Mutate the value of cooldown.
 		///
 		/// # Parameters
-		/// - `cooldown` - New cooldown time.
+		/// - `cooldown` - New cooldown time, counted in `cooldown_unit`.
 		#[ink(message)]
-		pub fn set_cooldown(&mut self, cooldown: BlockNumber) -> Result<(), FaucetError> {
-			self.ensure_owner()?;
+		pub fn set_cooldown(&mut self, cooldown: Duration) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			let old = self.cooldown;
+			self.cooldown = cooldown;
+			self.env().emit_event(CooldownChanged { old, new: cooldown });
+			Ok(())
+		}
+
+		/// Change the unit cooldowns are counted in, along with the cooldown itself.
+		/// Requests recorded in the previous unit are forgotten if the previous cooldown had
+		/// ended, and are otherwise considered to have happened now, so accounts in cooldown
+		/// wait at most a full `cooldown` from the change. Asset requests are always considered
+		/// to have happened now, and per-asset cooldowns are read in the new unit as they are.
+		///
+		/// # Parameters
+		/// - `cooldown_unit` - New unit.
+		/// - `cooldown` - New cooldown, counted in `cooldown_unit`.
+		#[ink(message)]
+		pub fn set_cooldown_unit(
+			&mut self,
+			cooldown_unit: CooldownUnit,
+			cooldown: Duration,
+		) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			let old_unit = self.cooldown_unit;
+			if cooldown_unit != old_unit {
+				let previous_at = self.now();
+				self.cooldown_unit = cooldown_unit;
+				self.cooldown_unit_change = Some(CooldownUnitChange {
+					at: self.now(),
+					previous_at,
+					previous_cooldown: self.cooldown,
+				});
+				self.env().emit_event(CooldownUnitChanged { old: old_unit, new: cooldown_unit });
+			}
+			let old = self.cooldown;
 			self.cooldown = cooldown;
+			self.env().emit_event(CooldownChanged { old, new: cooldown });
+			Ok(())
+		}
+
+		/// Rewrite the last request of `accounts` in `cooldown_unit`, if recorded in another
+		/// unit. Cooldowns are unaffected, as such requests are already considered to have
+		/// happened when the unit was changed, or forgotten if their cooldown had ended.
+		///
+		/// # Parameters
+		/// - `accounts` - Accounts whose last request to migrate.
+		#[ink(message)]
+		pub fn migrate_last_requests(
+			&mut self,
+			accounts: Vec<AccountId>,
+		) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			for account in accounts {
+				let Some(instant) = self.last_request_of.get(account) else {
+					continue;
+				};
+				if instant.unit() == self.cooldown_unit {
+					continue;
+				}
+				let last_amount = self.last_amount_of.get(account).unwrap_or(self.drip_amount);
+				match self.in_cooldown_unit(instant, self.previous_cooldown_for(last_amount)) {
+					Some(instant) => {
+						self.last_request_of.insert(account, &instant);
+					}
+					None => self.last_request_of.remove(account),
+				}
+			}
+			Ok(())
+		}
+
+		/// Set whether `drip_to` also puts the caller in cooldown.
+		///
+		/// # Parameters
+		/// - `cooldown_caller` - Whether the caller's cooldown is applied.
+		#[ink(message)]
+		pub fn set_cooldown_caller(&mut self, cooldown_caller: bool) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			let old = self.cooldown_caller;
+			self.cooldown_caller = cooldown_caller;
+			self.env().emit_event(CooldownCallerChanged { old, new: cooldown_caller });
+			Ok(())
+		}
+
+		/// Mutate the maximum amount per `drip_amount_of` request.
+		///
+		/// # Parameters
+		/// - `max_request_amount` - New maximum amount.
+		#[ink(message)]
+		pub fn set_max_request_amount(
+			&mut self,
+			max_request_amount: Balance,
+		) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			let old = self.max_request_amount;
+			self.max_request_amount = max_request_amount;
+			self.env().emit_event(MaxRequestAmountChanged { old, new: max_request_amount });
+			Ok(())
+		}
+
+		/// Change how the amount dripped per request is derived from the faucet's balance.
+		///
+		/// # Parameters
+		/// - `drip_curve` - New drip curve.
+		#[ink(message)]
+		pub fn set_drip_curve(&mut self, drip_curve: DripCurve) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			let old = self.drip_curve;
+			self.drip_curve = drip_curve;
+			self.env().emit_event(DripCurveChanged { old, new: drip_curve });
+			Ok(())
+		}
+
+		/// Set or remove the low-water mark. The alert is re-armed, so `LowBalance` is emitted on
+		/// the next drip if the balance is already below the new mark.
+		///
+		/// # Parameters
+		/// - `low_water_mark` - New low-water mark, or `None` to disable alerts.
+		#[ink(message)]
+		pub fn set_low_water_mark(
+			&mut self,
+			low_water_mark: Option<LowWaterMark>,
+		) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			let old = self.low_water_mark;
+			self.low_water_mark = low_water_mark;
+			self.low_balance_alerted = false;
+			self.env().emit_event(LowWaterMarkChanged { old, new: low_water_mark });
+			Ok(())
+		}
+
+		/// Change the length of the window the drip rate is observed over. Drips observed so
+		/// far are discarded.
+		///
+		/// # Parameters
+		/// - `stats_window` - New length, in blocks.
+		#[ink(message)]
+		pub fn set_stats_window(&mut self, stats_window: BlockNumber) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			let old = self.stats_window;
+			self.stats_window = stats_window;
+			self.previous_window_dripped = 0;
+			self.current_window_dripped = 0;
+			self.env().emit_event(StatsWindowChanged { old, new: stats_window });
+			Ok(())
+		}
+
+		/// Change how often an account can drip. Bucket states are kept across changes.
+		///
+		/// # Parameters
+		/// - `rate_limit` - New rate limit.
+		#[ink(message)]
+		pub fn set_rate_limit(&mut self, rate_limit: RateLimit) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			let old = self.rate_limit;
+			self.rate_limit = rate_limit;
+			self.env().emit_event(RateLimitChanged { old, new: rate_limit });
+			Ok(())
+		}
+
+		/// Change the per-account quotas. Drips already received keep counting against them.
+		/// The window quota allows at most `MAX_WINDOW_DRIPS` drips.
+		///
+		/// # Parameters
+		/// - `quotas` - New quotas.
+		#[ink(message)]
+		pub fn set_quotas(&mut self, quotas: Quotas) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			if quotas.window.is_some_and(|window| window.max_drips > MAX_WINDOW_DRIPS) {
+				return Err(FaucetError::InvalidQuota);
+			}
+			let old = self.quotas;
+			self.quotas = quotas;
+			self.env().emit_event(QuotasChanged { old, new: quotas });
+			Ok(())
+		}
+
+		/// Set or remove the global drip budget. Its period must last at least one block.
+		///
+		/// # Parameters
+		/// - `budget` - New global budget, or `None` to remove it.
+		#[ink(message)]
+		pub fn set_global_budget(
+			&mut self,
+			budget: Option<GlobalBudget>,
+		) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			if budget.is_some_and(|budget| budget.period == 0) {
+				return Err(FaucetError::InvalidPeriod);
+			}
+			let old = self.global_budget;
+			self.global_budget = budget;
+			self.env().emit_event(GlobalBudgetChanged { old, new: budget });
+			Ok(())
+		}
+
+		/// Change which accounts are eligible to drip.
+		///
+		/// # Parameters
+		/// - `mode` - New access mode.
+		#[ink(message)]
+		pub fn set_access_mode(&mut self, mode: AccessMode) -> Result<(), FaucetError> {
+			self.ensure_role(Role::ConfigManager)?;
+			let old = self.access_mode;
+			self.access_mode = mode;
+			self.env().emit_event(AccessModeChanged { old, new: mode });
+			Ok(())
+		}
+
+		/// Add accounts to the allowlist.
+		///
+		/// # Parameters
+		/// - `accounts` - Accounts to allow.
+		#[ink(message)]
+		pub fn add_to_allowlist(&mut self, accounts: Vec<AccountId>) -> Result<(), FaucetError> {
+			self.ensure_role(Role::Operator)?;
+			for account in accounts {
+				self.allowlist.insert(account, &());
+				self.env().emit_event(AllowlistChanged { account, allowed: true });
+			}
+			Ok(())
+		}
+
+		/// Remove accounts from the allowlist.
+		///
+		/// # Parameters
+		/// - `accounts` - Accounts to remove.
+		#[ink(message)]
+		pub fn remove_from_allowlist(
+			&mut self,
+			accounts: Vec<AccountId>,
+		) -> Result<(), FaucetError> {
+			self.ensure_role(Role::Operator)?;
+			for account in accounts {
+				self.allowlist.remove(account);
+				self.env().emit_event(AllowlistChanged { account, allowed: false });
+			}
+			Ok(())
+		}
+
+		/// Add accounts to the denylist, replacing any existing entries.
+		///
+		/// # Parameters
+		/// - `accounts` - Accounts to deny.
+		/// - `reason` - Application-defined code describing why the accounts are denied.
+		/// - `expires_at` - Last block at which the accounts are denied, if any.
+		#[ink(message)]
+		pub fn add_to_denylist(
+			&mut self,
+			accounts: Vec<AccountId>,
+			reason: u32,
+			expires_at: Option<BlockNumber>,
+		) -> Result<(), FaucetError> {
+			self.ensure_role(Role::Operator)?;
+			let entry = DenyEntry { reason, expires_at };
+			for account in accounts {
+				self.denylist.insert(account, &entry);
+				self.env().emit_event(DenylistChanged { account, entry: Some(entry) });
+			}
+			Ok(())
+		}
+
+		/// Remove accounts from the denylist.
+		///
+		/// # Parameters
+		/// - `accounts` - Accounts to remove.
+		#[ink(message)]
+		pub fn remove_from_denylist(
+			&mut self,
+			accounts: Vec<AccountId>,
+		) -> Result<(), FaucetError> {
+			self.ensure_role(Role::Operator)?;
+			for account in accounts {
+				self.denylist.remove(account);
+				self.env().emit_event(DenylistChanged { account, entry: None });
+			}
 			Ok(())
 		}
 
@@ -183,17 +2524,189 @@ mod fungibles {
 		/// The faucet will only drip tokens while active.
 		#[ink(message)]
 		pub fn start_stop(&mut self) -> Result<(), FaucetError> {
-			self.ensure_owner()?;
-			self.active = !self.active;
+			self.ensure_role(Role::Operator)?;
+			let old = self.active;
+			self.active = !old;
+			self.env().emit_event(ActivationChanged { old, new: self.active });
+			Ok(())
+		}
+
+		/// Send the transferred value to the faucet, crediting the caller as a donor.
+		#[ink(message, payable)]
+		pub fn fund(&mut self) -> Result<(), FaucetError> {
+			self.record_funding(self.env().caller(), self.env().transferred_value());
+			self.check_low_balance();
+			Ok(())
+		}
+
+		/// Withdraw native tokens from the faucet.
+		///
+		/// # Parameters
+		/// - `amount` - Amount of tokens to withdraw.
+		/// - `to` - Account receiving the tokens.
+		#[ink(message)]
+		pub fn withdraw(&mut self, amount: Balance, to: AccountId) -> Result<(), FaucetError> {
+			self.ensure_role(Role::Treasurer)?;
+			self.can_withdraw(amount)?;
+
+			self.transfer_native(to, amount)?;
+			self.env().emit_event(
+				Withdrawn {
+					asset_id: None,
+					value: amount,
+					to,
+				}
+			);
+			Ok(())
+		}
+
+		/// Withdraw all native tokens the faucet can spare, keeping its balance above 1.
+		///
+		/// # Parameters
+		/// - `to` - Account receiving the tokens.
+		#[ink(message)]
+		pub fn sweep(&mut self, to: AccountId) -> Result<(), FaucetError> {
+			// Largest amount allowed by `can_withdraw`.
+			let amount = self.env().balance().saturating_sub(2);
+			self.withdraw(amount, to)
+		}
+
+		/// Withdraw units of a fungible asset from the faucet. The asset does not need to be
+		/// registered, so that the balance of an unregistered asset can be recovered.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset.
+		/// - `amount` - Amount of the asset to withdraw.
+		/// - `to` - Account receiving the asset.
+		#[ink(message)]
+		pub fn withdraw_asset(
+			&mut self,
+			asset_id: TokenId,
+			amount: Balance,
+			to: AccountId,
+		) -> Result<(), FaucetError> {
+			self.ensure_role(Role::Treasurer)?;
+			self.can_withdraw_asset(asset_id, amount)?;
+
+			api::transfer(asset_id, to, amount)?;
+			self.env().emit_event(
+				Withdrawn {
+					asset_id: Some(asset_id),
+					value: amount,
+					to,
+				}
+			);
+			Ok(())
+		}
+
+		/// Withdraw all units of a fungible asset the faucet can spare, keeping its balance
+		/// above 1. The asset does not need to be registered.
+		///
+		/// # Parameters
+		/// - `asset_id` - Identifier of the asset.
+		/// - `to` - Account receiving the asset.
+		#[ink(message)]
+		pub fn sweep_asset(&mut self, asset_id: TokenId, to: AccountId) -> Result<(), FaucetError> {
+			// Largest amount allowed by `can_withdraw_asset`.
+			let amount = api::balance_of(asset_id, self.env().account_id())?.saturating_sub(2);
+			self.withdraw_asset(asset_id, amount, to)
+		}
+
+		/// Allow an attester to sign vouchers.
+		///
+		/// # Parameters
+		/// - `attester` - Compressed ECDSA public key of the attester.
+		#[ink(message)]
+		pub fn add_attester(&mut self, attester: AttesterKey) -> Result<(), FaucetError> {
+			self.ensure_role(Role::Admin)?;
+			self.attesters.insert(attester, &());
+			self.env().emit_event(AttesterChanged { attester, allowed: true });
+			Ok(())
+		}
+
+		/// Stop accepting vouchers signed by an attester.
+		///
+		/// # Parameters
+		/// - `attester` - Compressed ECDSA public key of the attester.
+		#[ink(message)]
+		pub fn remove_attester(&mut self, attester: AttesterKey) -> Result<(), FaucetError> {
+			self.ensure_role(Role::Admin)?;
+			self.attesters.remove(attester);
+			self.env().emit_event(AttesterChanged { attester, allowed: false });
+			Ok(())
+		}
+
+		/// Grant a role to an account.
+		///
+		/// # Parameters
+		/// - `role` - Role to grant.
+		/// - `account` - Account receiving the role.
+		#[ink(message)]
+		pub fn grant_role(&mut self, role: Role, account: AccountId) -> Result<(), FaucetError> {
+			self.ensure_role(Role::Admin)?;
+			if !self.roles.contains((role, account)) {
+				self.roles.insert((role, account), &());
+				self.env().emit_event(
+					RoleGranted {
+						role,
+						account,
+						by: self.env().caller(),
+					}
+				);
+			}
+			Ok(())
+		}
+
+		/// Revoke a role previously granted to an account.
+		///
+		/// # Parameters
+		/// - `role` - Role to revoke.
+		/// - `account` - Account losing the role.
+		#[ink(message)]
+		pub fn revoke_role(&mut self, role: Role, account: AccountId) -> Result<(), FaucetError> {
+			self.ensure_role(Role::Admin)?;
+			if self.roles.contains((role, account)) {
+				self.roles.remove((role, account));
+				self.env().emit_event(
+					RoleRevoked {
+						role,
+						account,
+						by: self.env().caller(),
+					}
+				);
+			}
+			Ok(())
+		}
+
+		/// Give up a role granted to the caller.
+		///
+		/// # Parameters
+		/// - `role` - Role to renounce.
+		#[ink(message)]
+		pub fn renounce_role(&mut self, role: Role) -> Result<(), FaucetError> {
+			let caller = self.env().caller();
+			if !self.roles.contains((role, caller)) {
+				return Err(FaucetError::MissingRole);
+			}
+			self.roles.remove((role, caller));
+			self.env().emit_event(
+				RoleRevoked {
+					role,
+					account: caller,
+					by: caller,
+				}
+			);
 			Ok(())
 		}
 
-		/// Removes the owner of the contract.
+		/// Removes the owner of the contract, along with any pending ownership transfer.
 		/// Effectively disable any action that requires ownership authorization.
 		#[ink(message)]
 		pub fn remove_ownership(&mut self) -> Result<(), FaucetError> {
 			self.ensure_owner()?;
 			self.owner = None;
+			self.pending_owner = None;
+			self.env().emit_event(OwnershipRenounced { previous_owner: self.env().caller() });
 			Ok(())
 		}
 
@@ -203,19 +2716,74 @@ mod fungibles {
 		/// - `drip_amount` - New drip amount.
 		#[ink(message)]
 		pub fn set_drip_amount(&mut self, drip_amount: Balance) -> Result<(), FaucetError> {
-			self.ensure_owner()?;
+			self.ensure_role(Role::ConfigManager)?;
+			let old = self.drip_amount;
 			self.drip_amount = drip_amount;
+			self.env().emit_event(DripAmountChanged { old, new: drip_amount });
 			Ok(())
 		}
 
-		/// Transfer the ownership of the contract to another account.
+		/// Start transferring the ownership of the contract to another account.
+		/// The transfer only takes effect once the new owner calls `accept_ownership`.
+		/// Replaces any transfer already pending.
 		///
 		/// # Parameters
-		/// - `owner` - New owner account.
+		/// - `new_owner` - New owner account.
+		/// - `expires_at` - Last block at which the transfer can be accepted, if any.
+		#[ink(message)]
+		pub fn transfer_ownership(
+			&mut self,
+			new_owner: AccountId,
+			expires_at: Option<BlockNumber>,
+		) -> Result<(), FaucetError> {
+			self.ensure_owner()?;
+			self.pending_owner = Some(PendingOwnership { owner: new_owner, expires_at });
+			self.env().emit_event(
+				OwnershipTransferStarted {
+					from: self.env().caller(),
+					to: new_owner,
+					expires_at,
+				}
+			);
+			Ok(())
+		}
+
+		/// Accept a pending ownership transfer. Must be called by the pending owner.
+		#[ink(message)]
+		pub fn accept_ownership(&mut self) -> Result<(), FaucetError> {
+			let pending = self.pending_owner.ok_or(FaucetError::NoPendingOwner)?;
+			let caller = self.env().caller();
+			if pending.owner != caller {
+				return Err(FaucetError::NotPendingOwner);
+			}
+			if let Some(expired_at) = pending.expires_at {
+				if expired_at < self.env().block_number() {
+					return Err(FaucetError::OwnershipTransferExpired { expired_at });
+				}
+			}
+
+			let previous_owner = self.owner;
+			self.owner = Some(caller);
+			self.pending_owner = None;
+			self.env().emit_event(
+				OwnershipTransferred {
+					from: previous_owner,
+					to: caller,
+				}
+			);
+			Ok(())
+		}
+
+		/// Cancel a pending ownership transfer.
 		#[ink(message)]
-		pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), FaucetError> {
+		pub fn cancel_ownership_transfer(&mut self) -> Result<(), FaucetError> {
 			self.ensure_owner()?;
-			self.owner = Some(new_owner);
+			let pending = self.pending_owner.take().ok_or(FaucetError::NoPendingOwner)?;
+			self.env().emit_event(
+				OwnershipTransferCancelled {
+					to: pending.owner,
+				}
+			);
 			Ok(())
 		}
 	}
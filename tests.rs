@@ -0,0 +1,1580 @@
+use drink::{
+	call,
+	devnet::{AccountId, Balance, Runtime},
+	last_contract_event,
+	sandbox_api::{
+		assets_api::AssetsAPI, balance_api::BalanceAPI, system_api::SystemAPI,
+		timestamp_api::TimestampAPI,
+	},
+	session::Session,
+	BlockBuilder, TestExternalities, NO_SALT,
+};
+use ink::scale::{Decode, Encode};
+use sp_runtime::app_crypto::sp_core::{ecdsa, Pair};
+
+use super::*;
+use crate::fungibles::{MintConfig, PendingOwnership, RemainingQuota, Runway};
+
+type BlockNumber = u32;
+type Timestamp = u64;
+
+const UNIT: Balance = 10_000_000_000;
+const INIT_AMOUNT: Balance = 100_000_000 * UNIT;
+const INIT_VALUE: Balance = 100 * UNIT;
+const DRIP_AMOUNT: Balance = UNIT;
+const COOLDOWN: BlockNumber = 10;
+const COOLDOWN_MS: Timestamp = 60_000;
+const START_TIMESTAMP: Timestamp = 1_700_000_000_000;
+const ASSET: TokenId = 1;
+const ALICE: AccountId = AccountId::new([1u8; 32]);
+const BOB: AccountId = AccountId::new([2_u8; 32]);
+const CHARLIE: AccountId = AccountId::new([3_u8; 32]);
+
+#[drink::contract_bundle_provider]
+enum BundleProvider {}
+
+/// Sandbox environment for Pop Devnet Runtime.
+pub struct Pop {
+	ext: TestExternalities,
+}
+
+impl Default for Pop {
+	fn default() -> Self {
+		// Initialising genesis state, providing accounts with an initial balance.
+		let balances: Vec<(AccountId, u128)> =
+			vec![(ALICE, INIT_AMOUNT), (BOB, INIT_AMOUNT), (CHARLIE, INIT_AMOUNT)];
+		let ext = BlockBuilder::<Runtime>::new_ext(balances);
+		Self { ext }
+	}
+}
+
+// Implement core functionalities for the `Pop` sandbox.
+drink::impl_sandbox!(Pop, Runtime, ALICE);
+
+#[drink::test(sandbox = Pop)]
+fn new_constructor_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+
+	assert_eq!(cooldown(&mut session), Timestamp::from(COOLDOWN));
+	assert_eq!(drip_amount(&mut session), DRIP_AMOUNT);
+	assert!(!is_active(&mut session));
+	assert_eq!(owner(&mut session), Some(contract_account(&ALICE)));
+	assert_eq!(last_request_of(&mut session, ALICE), None);
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+
+	session.set_actor(BOB);
+	let balance_before = session.sandbox().free_balance(&BOB);
+	assert_eq!(drip(&mut session), Ok(()));
+	let block = session.sandbox().block_number();
+
+	assert_eq!(session.sandbox().free_balance(&BOB), balance_before + DRIP_AMOUNT);
+	assert_eq!(last_request_of(&mut session, BOB), Some(Instant::Block(block)));
+	assert_eq!(last_contract_event(&session), Some((DRIP_AMOUNT, BOB).encode().as_slice()));
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_fails_when_not_active(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+
+	session.set_actor(BOB);
+	assert_eq!(drip(&mut session), Err(FaucetError::NotActive));
+
+	// Deactivated after having been active.
+	session.set_actor(ALICE);
+	assert_eq!(start_stop(&mut session), Ok(()));
+	assert_eq!(start_stop(&mut session), Ok(()));
+	session.set_actor(BOB);
+	assert_eq!(drip(&mut session), Err(FaucetError::NotActive));
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_fails_in_cooldown(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+
+	session.set_actor(BOB);
+	assert_eq!(drip(&mut session), Ok(()));
+	let available_at = Instant::Block(session.sandbox().block_number() + COOLDOWN);
+	assert_eq!(drip(&mut session), Err(FaucetError::InCoolDown { available_at }));
+	// Still in cooldown one block before it ends.
+	session.sandbox().build_blocks(COOLDOWN - 1);
+	assert_eq!(drip(&mut session), Err(FaucetError::InCoolDown { available_at }));
+	// Cooldown is per account.
+	session.set_actor(CHARLIE);
+	assert_eq!(drip(&mut session), Ok(()));
+	// Cooldown is over.
+	session.set_actor(BOB);
+	session.sandbox().build_blocks(1);
+	assert_eq!(drip(&mut session), Ok(()));
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_fails_with_not_enough_funds(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, INIT_VALUE, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+
+	session.set_actor(BOB);
+	assert!(matches!(
+		drip(&mut session),
+		Err(FaucetError::NotEnoughFunds { required, .. }) if required == INIT_VALUE + 2
+	));
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_to_fails_below_existential_deposit(mut session: Session) {
+	let _ = env_logger::try_init();
+	// A single unit is below the existential deposit of a fresh account.
+	deploy(&mut session, COOLDOWN, 1, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+
+	let fresh = AccountId::new([9u8; 32]);
+	assert_eq!(drip_to(&mut session, fresh), Err(FaucetError::BelowExistentialDeposit));
+	// Nothing has been recorded for the failed drip.
+	assert_eq!(session.sandbox().free_balance(&fresh), 0);
+	assert_eq!(drip_to(&mut session, fresh), Err(FaucetError::BelowExistentialDeposit));
+}
+
+#[drink::test(sandbox = Pop)]
+fn cooldown_in_milliseconds_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	session.sandbox().set_timestamp(START_TIMESTAMP);
+	deploy_with_cooldown_unit(&mut session, COOLDOWN_MS, DRIP_AMOUNT, "Milliseconds", INIT_VALUE)
+		.unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+
+	session.set_actor(BOB);
+	assert_eq!(drip(&mut session), Ok(()));
+	assert_eq!(last_request_of(&mut session, BOB), Some(Instant::Timestamp(START_TIMESTAMP)));
+	let available_at = Instant::Timestamp(START_TIMESTAMP + COOLDOWN_MS);
+	session.sandbox().set_timestamp(START_TIMESTAMP + COOLDOWN_MS - 1);
+	assert_eq!(drip(&mut session), Err(FaucetError::InCoolDown { available_at }));
+	session.sandbox().set_timestamp(START_TIMESTAMP + COOLDOWN_MS);
+	assert_eq!(drip(&mut session), Ok(()));
+}
+
+#[drink::test(sandbox = Pop)]
+fn set_cooldown_unit_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	session.sandbox().set_timestamp(START_TIMESTAMP);
+	let contract = deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+	session.sandbox().create(&ASSET, &ALICE, 1).unwrap();
+	session.sandbox().mint_into(&ASSET, &contract, 10 * DRIP_AMOUNT).unwrap();
+	assert_eq!(register_asset(&mut session, ASSET, DRIP_AMOUNT, COOLDOWN.into()), Ok(()));
+	session.set_actor(CHARLIE);
+	assert_eq!(drip(&mut session), Ok(()));
+	assert_eq!(drip_asset(&mut session, ASSET), Ok(()));
+	session.sandbox().build_blocks(COOLDOWN);
+	session.set_actor(BOB);
+	assert_eq!(drip(&mut session), Ok(()));
+
+	session.set_actor(ALICE);
+	let switched_at = START_TIMESTAMP + 1_000;
+	session.sandbox().set_timestamp(switched_at);
+	assert_eq!(set_cooldown_unit(&mut session, "Milliseconds", COOLDOWN_MS), Ok(()));
+	assert_eq!(cooldown(&mut session), COOLDOWN_MS);
+	// Charlie's cooldown, recorded in blocks, had ended before the switch.
+	assert_eq!(last_request_of(&mut session, CHARLIE), None);
+	assert_eq!(eligibility(&mut session, CHARLIE), Ok(()));
+	// Asset requests are kept, as their cooldown in blocks is not recorded.
+	assert_eq!(
+		last_asset_request_of(&mut session, ASSET, CHARLIE),
+		Some(Instant::Timestamp(switched_at))
+	);
+	// Bob's drip, still in cooldown, is considered to have happened at the switch.
+	assert_eq!(last_request_of(&mut session, BOB), Some(Instant::Timestamp(switched_at)));
+	let available_at = Instant::Timestamp(switched_at + COOLDOWN_MS);
+	assert_eq!(eligibility(&mut session, BOB), Err(FaucetError::InCoolDown { available_at }));
+	// Migrating the entries eagerly does not change the cooldowns.
+	assert_eq!(migrate_last_requests(&mut session, vec![BOB, CHARLIE]), Ok(()));
+	assert_eq!(last_request_of(&mut session, BOB), Some(Instant::Timestamp(switched_at)));
+	assert_eq!(last_request_of(&mut session, CHARLIE), None);
+	assert_eq!(eligibility(&mut session, BOB), Err(FaucetError::InCoolDown { available_at }));
+	assert_eq!(eligibility(&mut session, CHARLIE), Ok(()));
+	// Only a `ConfigManager` can change the unit.
+	session.set_actor(BOB);
+	assert_eq!(
+		set_cooldown_unit(&mut session, "Blocks", COOLDOWN.into()),
+		Err(FaucetError::MissingRole)
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_to_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+
+	session.set_actor(BOB);
+	let balance_before = session.sandbox().free_balance(&CHARLIE);
+	assert_eq!(drip_to(&mut session, CHARLIE), Ok(()));
+	assert_eq!(session.sandbox().free_balance(&CHARLIE), balance_before + DRIP_AMOUNT);
+	assert_eq!(last_contract_event(&session), Some((DRIP_AMOUNT, CHARLIE).encode().as_slice()));
+	// The cooldown is tracked on the beneficiary only.
+	let block = session.sandbox().block_number();
+	assert_eq!(last_request_of(&mut session, CHARLIE), Some(Instant::Block(block)));
+	assert_eq!(last_request_of(&mut session, BOB), None);
+	let available_at = Instant::Block(block + COOLDOWN);
+	assert_eq!(drip_to(&mut session, CHARLIE), Err(FaucetError::InCoolDown { available_at }));
+
+	// Unless the caller is put in cooldown as well.
+	session.set_actor(ALICE);
+	assert_eq!(set_cooldown_caller(&mut session, true), Ok(()));
+	session.set_actor(BOB);
+	assert_eq!(drip_to(&mut session, ALICE), Ok(()));
+	assert_eq!(drip(&mut session), Err(FaucetError::InCoolDown { available_at }));
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_to_location_rejects_invalid_locations(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+
+	// Only accounts on sibling parachains can receive drips.
+	let account = format!("AccountId32 {{ network: None, id: {} }}", hex(&[2u8; 32]));
+	for location in [
+		"V4(Location { parents: 0, interior: Here })".to_string(),
+		"V4(Location { parents: 1, interior: X1([Parachain(2000)]) })".to_string(),
+		format!("V4(Location {{ parents: 0, interior: X1([{account}]) }})"),
+		format!("V4(Location {{ parents: 2, interior: X2([Parachain(2000), {account}]) }})"),
+		format!(
+			"V4(Location {{ parents: 1, interior: X3([Parachain(2000), PalletInstance(10), \
+			 {account}]) }})"
+		),
+	] {
+		assert_eq!(drip_to_location(&mut session, &location), Err(FaucetError::InvalidLocation));
+	}
+	// A valid location is accepted, before the faucet's own checks apply.
+	let location =
+		format!("V4(Location {{ parents: 1, interior: X2([Parachain(2000), {account}]) }})");
+	assert_eq!(drip_to_location(&mut session, &location), Err(FaucetError::NotActive));
+}
+
+#[drink::test(sandbox = Pop)]
+fn eligibility_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(eligibility(&mut session, BOB), Err(FaucetError::NotActive));
+
+	assert_eq!(start_stop(&mut session), Ok(()));
+	assert_eq!(eligibility(&mut session, BOB), Ok(()));
+
+	session.set_actor(BOB);
+	assert_eq!(drip(&mut session), Ok(()));
+	// Any account can query the eligibility of another one.
+	session.set_actor(CHARLIE);
+	let available_at = Instant::Block(session.sandbox().block_number() + COOLDOWN);
+	assert_eq!(eligibility(&mut session, BOB), Err(FaucetError::InCoolDown { available_at }));
+	assert_eq!(eligibility(&mut session, CHARLIE), Ok(()));
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_respects_global_budget(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, 0, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+	assert_eq!(
+		set_global_budget(&mut session, "Some(GlobalBudget { limit: 1, period: 0 })"),
+		Err(FaucetError::InvalidPeriod)
+	);
+	let budget = format!("Some(GlobalBudget {{ limit: {}, period: 100 }})", 2 * DRIP_AMOUNT);
+	assert_eq!(set_global_budget(&mut session, &budget), Ok(()));
+
+	session.set_actor(BOB);
+	assert_eq!(drip(&mut session), Ok(()));
+	session.set_actor(CHARLIE);
+	assert_eq!(drip(&mut session), Ok(()));
+	let resets_at = session.sandbox().block_number() / 100 * 100 + 100;
+	assert_eq!(
+		drip(&mut session),
+		Err(FaucetError::GlobalBudgetExhausted { remaining: 0, resets_at })
+	);
+	// The budget is replenished when the next period starts.
+	let block = session.sandbox().block_number();
+	session.sandbox().build_blocks(resets_at - block);
+	assert_eq!(drip(&mut session), Ok(()));
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_respects_quotas(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, 0, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+	assert_eq!(
+		set_quotas(
+			&mut session,
+			"Quotas { lifetime: Some(3), window: Some(WindowQuota { max_drips: 2, length: 20 }) }"
+		),
+		Ok(())
+	);
+	assert_eq!(
+		remaining_quota(&mut session, BOB),
+		RemainingQuota { lifetime: Some(3), window: Some(2) }
+	);
+
+	session.set_actor(BOB);
+	assert_eq!(drip(&mut session), Ok(()));
+	let available_at = session.sandbox().block_number() + 20;
+	assert_eq!(drip(&mut session), Ok(()));
+	assert_eq!(
+		remaining_quota(&mut session, BOB),
+		RemainingQuota { lifetime: Some(1), window: Some(0) }
+	);
+	assert_eq!(drip(&mut session), Err(FaucetError::WindowQuotaExhausted { available_at }));
+
+	// Drips leave the window once it moved past them.
+	session.sandbox().build_blocks(20);
+	assert_eq!(drip(&mut session), Ok(()));
+	assert_eq!(drip(&mut session), Err(FaucetError::LifetimeQuotaExhausted));
+}
+
+#[drink::test(sandbox = Pop)]
+fn window_quota_is_rolling(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, 0, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+	let quotas = |max_drips: u32| {
+		let window = format!("WindowQuota {{ max_drips: {max_drips}, length: 20 }}");
+		format!("Quotas {{ lifetime: None, window: Some({window}) }}")
+	};
+	assert_eq!(set_quotas(&mut session, &quotas(65)), Err(FaucetError::InvalidQuota));
+	assert_eq!(set_quotas(&mut session, &quotas(2)), Ok(()));
+
+	session.set_actor(BOB);
+	let first = session.sandbox().block_number();
+	assert_eq!(drip(&mut session), Ok(()));
+	session.sandbox().build_blocks(10);
+	assert_eq!(drip(&mut session), Ok(()));
+	assert_eq!(
+		drip(&mut session),
+		Err(FaucetError::WindowQuotaExhausted { available_at: first + 20 })
+	);
+	// Only the first drip left the window, so a single drip is allowed at the boundary.
+	session.sandbox().build_blocks(10);
+	assert_eq!(drip(&mut session), Ok(()));
+	assert_eq!(
+		drip(&mut session),
+		Err(FaucetError::WindowQuotaExhausted { available_at: first + 30 })
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_amount_of_scales_cooldown(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+
+	session.set_actor(BOB);
+	assert_eq!(drip_amount_of(&mut session, 0), Err(FaucetError::ZeroAmount));
+	assert_eq!(
+		drip_amount_of(&mut session, 2 * DRIP_AMOUNT),
+		Err(FaucetError::AmountAboveMaximum { maximum: DRIP_AMOUNT })
+	);
+	let balance_before = session.sandbox().free_balance(&BOB);
+	assert_eq!(drip_amount_of(&mut session, DRIP_AMOUNT / 2), Ok(()));
+	assert_eq!(session.sandbox().free_balance(&BOB), balance_before + DRIP_AMOUNT / 2);
+	// Half the amount, half the cooldown.
+	let available_at = Instant::Block(session.sandbox().block_number() + COOLDOWN / 2);
+	assert_eq!(drip(&mut session), Err(FaucetError::InCoolDown { available_at }));
+	session.sandbox().build_blocks(COOLDOWN / 2);
+	// A full drip applies the full cooldown again.
+	assert_eq!(drip(&mut session), Ok(()));
+	let available_at = Instant::Block(session.sandbox().block_number() + COOLDOWN);
+	assert_eq!(
+		drip_amount_of(&mut session, DRIP_AMOUNT / 2),
+		Err(FaucetError::InCoolDown { available_at })
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_amount_of_draws_from_token_bucket(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+	assert_eq!(set_max_request_amount(&mut session, 3 * UNIT), Ok(()));
+	let bucket =
+		format!("TokenBucket(TokenBucket {{ refill_per_block: {UNIT}, burst: {} }})", 3 * UNIT);
+	assert_eq!(set_rate_limit(&mut session, &bucket), Ok(()));
+
+	session.set_actor(BOB);
+	// Accounts that never dripped start with a full bucket.
+	assert_eq!(allowance_of(&mut session, BOB), Some(3 * UNIT));
+	let balance_before = session.sandbox().free_balance(&BOB);
+	assert_eq!(drip_amount_of(&mut session, 2 * UNIT), Ok(()));
+	assert_eq!(session.sandbox().free_balance(&BOB), balance_before + 2 * UNIT);
+	assert_eq!(
+		drip_amount_of(&mut session, 2 * UNIT),
+		Err(FaucetError::AllowanceExceeded { allowance: UNIT })
+	);
+	// Small drips remain possible right away.
+	assert_eq!(drip_amount_of(&mut session, UNIT / 2), Ok(()));
+
+	// Allowance accrues per block, up to the burst.
+	session.sandbox().build_blocks(2);
+	assert_eq!(allowance_of(&mut session, BOB), Some(5 * UNIT / 2));
+	session.sandbox().build_blocks(10);
+	assert_eq!(allowance_of(&mut session, BOB), Some(3 * UNIT));
+	assert_eq!(drip_amount_of(&mut session, 3 * UNIT), Ok(()));
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_follows_drip_curve(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+	assert_eq!(effective_drip_amount(&mut session), DRIP_AMOUNT);
+
+	// Balance is above the threshold, so the drip amount is not tapered yet.
+	let taper = format!("LinearTaper {{ threshold: {}, min: 0 }}", INIT_VALUE / 2);
+	assert_eq!(set_drip_curve(&mut session, &taper), Ok(()));
+	assert_eq!(effective_drip_amount(&mut session), DRIP_AMOUNT);
+	// Balance is below the threshold.
+	let taper = format!("LinearTaper {{ threshold: {}, min: 0 }}", 2 * INIT_VALUE);
+	assert_eq!(set_drip_curve(&mut session, &taper), Ok(()));
+	let amount = effective_drip_amount(&mut session);
+	assert!(amount > 0 && amount < DRIP_AMOUNT);
+
+	// A thousandth of the balance, clamped to the minimum.
+	let percentage =
+		format!("Percentage {{ per_mill: 1, min: {}, max: {DRIP_AMOUNT} }}", DRIP_AMOUNT / 4);
+	assert_eq!(set_drip_curve(&mut session, &percentage), Ok(()));
+	assert_eq!(effective_drip_amount(&mut session), DRIP_AMOUNT / 4);
+	// Requests cannot exceed the effective drip amount.
+	session.set_actor(CHARLIE);
+	assert_eq!(
+		drip_amount_of(&mut session, DRIP_AMOUNT / 2),
+		Err(FaucetError::AmountAboveMaximum { maximum: DRIP_AMOUNT / 4 })
+	);
+
+	session.set_actor(BOB);
+	let balance_before = session.sandbox().free_balance(&BOB);
+	assert_eq!(drip(&mut session), Ok(()));
+	assert_eq!(session.sandbox().free_balance(&BOB), balance_before + DRIP_AMOUNT / 4);
+	assert_eq!(last_contract_event(&session), Some((DRIP_AMOUNT / 4, BOB).encode().as_slice()));
+	// A smaller drip due to the curve is still followed by the full cooldown.
+	let available_at = Instant::Block(session.sandbox().block_number() + COOLDOWN);
+	assert_eq!(drip(&mut session), Err(FaucetError::InCoolDown { available_at }));
+
+	// Nothing is dripped once the curve drops to zero.
+	session.set_actor(ALICE);
+	let percentage = format!("Percentage {{ per_mill: 0, min: 0, max: {DRIP_AMOUNT} }}");
+	assert_eq!(set_drip_curve(&mut session, &percentage), Ok(()));
+	assert_eq!(effective_drip_amount(&mut session), 0);
+	session.set_actor(CHARLIE);
+	assert_eq!(drip(&mut session), Err(FaucetError::ZeroAmount));
+	assert_eq!(drip_to(&mut session, ALICE), Err(FaucetError::ZeroAmount));
+	let account = format!("AccountId32 {{ network: None, id: {} }}", hex(&[2u8; 32]));
+	let location =
+		format!("V4(Location {{ parents: 1, interior: X2([Parachain(2000), {account}]) }})");
+	assert_eq!(drip_to_location(&mut session, &location), Err(FaucetError::ZeroAmount));
+}
+
+#[drink::test(sandbox = Pop)]
+fn fund_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	// Deployer's endowment is recorded as a donation.
+	assert_eq!(total_received(&mut session), INIT_VALUE);
+	assert_eq!(donations_of(&mut session, ALICE), INIT_VALUE);
+
+	session.set_actor(BOB);
+	assert_eq!(fund(&mut session, 2 * UNIT), Ok(()));
+	assert_eq!(last_contract_event(&session), Some((BOB, 2 * UNIT).encode().as_slice()));
+	assert_eq!(fund(&mut session, UNIT), Ok(()));
+	session.set_actor(CHARLIE);
+	assert_eq!(fund(&mut session, 2 * INIT_VALUE), Ok(()));
+
+	assert_eq!(donations_of(&mut session, BOB), 3 * UNIT);
+	assert_eq!(total_received(&mut session), 3 * INIT_VALUE + 3 * UNIT);
+	assert_eq!(
+		top_donors(&mut session),
+		vec![
+			(contract_account(&CHARLIE), 2 * INIT_VALUE),
+			(contract_account(&ALICE), INIT_VALUE),
+			(contract_account(&BOB), 3 * UNIT),
+		]
+	);
+
+	session.set_actor(ALICE);
+	assert_eq!(start_stop(&mut session), Ok(()));
+	assert_eq!(drip(&mut session), Ok(()));
+	assert_eq!(total_dripped(&mut session), DRIP_AMOUNT);
+}
+
+#[drink::test(sandbox = Pop)]
+fn stats_and_runway_work(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+	// Nothing dripped yet, so there is no rate to estimate a runway in blocks from.
+	let Runway { drips, blocks } = runway(&mut session);
+	assert!(drips >= INIT_VALUE / DRIP_AMOUNT - 1);
+	assert_eq!(blocks, None);
+
+	session.set_actor(BOB);
+	assert_eq!(drip(&mut session), Ok(()));
+	session.set_actor(CHARLIE);
+	assert_eq!(drip(&mut session), Ok(()));
+	session.sandbox().build_blocks(COOLDOWN);
+	session.set_actor(BOB);
+	assert_eq!(drip(&mut session), Ok(()));
+
+	assert_eq!(drip_count(&mut session), 3);
+	assert_eq!(unique_recipients(&mut session), 2);
+	assert_eq!(total_dripped(&mut session), 3 * DRIP_AMOUNT);
+	let Runway { drips: drips_after, blocks } = runway(&mut session);
+	assert_eq!(drips_after, drips - 3);
+	assert!(blocks.is_some_and(|blocks| blocks > 0));
+}
+
+#[drink::test(sandbox = Pop)]
+fn low_balance_is_emitted_once_per_crossing(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+	let threshold = INIT_VALUE - DRIP_AMOUNT / 2;
+	let mark = format!("Some(LowWaterMark {{ threshold: {threshold}, hysteresis: {} }})", UNIT / 4);
+	assert_eq!(set_low_water_mark(&mut session, &mark), Ok(()));
+
+	// Crossing the mark raises an alert.
+	session.set_actor(BOB);
+	assert_eq!(drip(&mut session), Ok(()));
+	let event = last_contract_event(&session).unwrap();
+	let (remaining, alert_threshold) = <(Balance, Balance)>::decode(&mut &event[..]).unwrap();
+	assert!(remaining < threshold);
+	assert_eq!(alert_threshold, threshold);
+	// Staying below it does not.
+	session.set_actor(CHARLIE);
+	assert_eq!(drip(&mut session), Ok(()));
+	assert_eq!(last_contract_event(&session), Some((DRIP_AMOUNT, CHARLIE).encode().as_slice()));
+	// Nor does rising within the hysteresis margin.
+	assert_eq!(fund(&mut session, DRIP_AMOUNT + DRIP_AMOUNT / 2), Ok(()));
+	session.sandbox().build_blocks(COOLDOWN);
+	assert_eq!(drip(&mut session), Ok(()));
+	assert_eq!(last_contract_event(&session), Some((DRIP_AMOUNT, CHARLIE).encode().as_slice()));
+	// Rising above the margin re-arms the alert.
+	assert_eq!(fund(&mut session, 5 * DRIP_AMOUNT / 2), Ok(()));
+	session.set_actor(BOB);
+	assert_eq!(drip(&mut session), Ok(()));
+	assert_eq!(last_contract_event(&session), Some((DRIP_AMOUNT, BOB).encode().as_slice()));
+	session.sandbox().build_blocks(COOLDOWN);
+	assert_eq!(drip(&mut session), Ok(()));
+	let event = last_contract_event(&session).unwrap();
+	let (remaining, _) = <(Balance, Balance)>::decode(&mut &event[..]).unwrap();
+	assert!(remaining < threshold);
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_asset_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	let contract = deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+	session.set_actor(BOB);
+	assert_eq!(drip_asset(&mut session, ASSET), Err(FaucetError::AssetNotRegistered));
+
+	session.set_actor(ALICE);
+	session.sandbox().create(&ASSET, &ALICE, 1).unwrap();
+	session.sandbox().mint_into(&ASSET, &contract, 10 * DRIP_AMOUNT).unwrap();
+	assert_eq!(register_asset(&mut session, ASSET, DRIP_AMOUNT, COOLDOWN.into()), Ok(()));
+	session.set_actor(BOB);
+	assert_eq!(drip_asset(&mut session, ASSET), Ok(()));
+	assert_eq!(session.sandbox().balance_of(&ASSET, &BOB), DRIP_AMOUNT);
+	assert_eq!(
+		last_contract_event(&session),
+		Some((ASSET, DRIP_AMOUNT, BOB).encode().as_slice())
+	);
+	let available_at = Instant::Block(session.sandbox().block_number() + COOLDOWN);
+	assert_eq!(drip_asset(&mut session, ASSET), Err(FaucetError::InCoolDown { available_at }));
+}
+
+#[drink::test(sandbox = Pop)]
+fn refill_requires_registered_asset_and_configuration(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(register_asset(&mut session, ASSET, DRIP_AMOUNT, COOLDOWN.into()), Ok(()));
+
+	assert_eq!(refill(&mut session, ASSET + 1), Err(FaucetError::AssetNotRegistered));
+	assert_eq!(refill(&mut session, ASSET), Err(FaucetError::RefillNotConfigured));
+	assert_eq!(set_refill(&mut session, ASSET + 1, "None"), Err(FaucetError::AssetNotRegistered));
+	assert_eq!(set_refill(&mut session, ASSET, "None"), Ok(()));
+	let refill_config = format!(
+		"Some(RefillConfig {{ treasury: {CHARLIE}, threshold: 1, chunk: 1, cap: 1, period: 0 }})"
+	);
+	assert_eq!(set_refill(&mut session, ASSET, &refill_config), Err(FaucetError::InvalidPeriod));
+	// Only a `ConfigManager` can configure refills, but anyone can trigger them.
+	session.set_actor(BOB);
+	assert_eq!(set_refill(&mut session, ASSET, "None"), Err(FaucetError::MissingRole));
+	assert_eq!(refill(&mut session, ASSET), Err(FaucetError::RefillNotConfigured));
+}
+
+#[drink::test(sandbox = Pop)]
+fn refill_pulls_from_treasury_within_cap(mut session: Session) {
+	let _ = env_logger::try_init();
+	const PERIOD: BlockNumber = 100;
+	let contract = deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(register_asset(&mut session, ASSET, DRIP_AMOUNT, COOLDOWN.into()), Ok(()));
+	// Charlie acts as treasury and approves the faucet to spend its units of the asset.
+	session.sandbox().create(&ASSET, &ALICE, 1).unwrap();
+	session.sandbox().mint_into(&ASSET, &CHARLIE, 10 * DRIP_AMOUNT).unwrap();
+	session.sandbox().approve(&ASSET, &CHARLIE, &contract, 10 * DRIP_AMOUNT).unwrap();
+	let (threshold, chunk, cap) = (10 * DRIP_AMOUNT, 2 * DRIP_AMOUNT, 3 * DRIP_AMOUNT);
+	let refill_config = format!(
+		"Some(RefillConfig {{ treasury: {CHARLIE}, threshold: {threshold}, chunk: {chunk}, \
+		 cap: {cap}, period: {PERIOD} }})"
+	);
+	assert_eq!(set_refill(&mut session, ASSET, &refill_config), Ok(()));
+
+	// The faucet holds none of the asset, a full chunk is pulled from the treasury.
+	session.set_actor(BOB);
+	assert_eq!(refill(&mut session, ASSET), Ok(()));
+	assert_eq!(session.sandbox().balance_of(&ASSET, &contract), 2 * DRIP_AMOUNT);
+	assert_eq!(session.sandbox().balance_of(&ASSET, &CHARLIE), 8 * DRIP_AMOUNT);
+	assert_eq!(
+		last_contract_event(&session),
+		Some((ASSET, CHARLIE, 2 * DRIP_AMOUNT).encode().as_slice())
+	);
+	// Only what is left of the cap is pulled.
+	assert_eq!(refill(&mut session, ASSET), Ok(()));
+	assert_eq!(session.sandbox().balance_of(&ASSET, &contract), 3 * DRIP_AMOUNT);
+	assert_eq!(
+		last_contract_event(&session),
+		Some((ASSET, CHARLIE, DRIP_AMOUNT).encode().as_slice())
+	);
+	let block = session.sandbox().block_number();
+	let resets_at = block - block % PERIOD + PERIOD;
+	assert_eq!(refill(&mut session, ASSET), Err(FaucetError::RefillCapExhausted { resets_at }));
+
+	// The cap resets with the next period.
+	session.sandbox().build_blocks(resets_at - block);
+	assert_eq!(refill(&mut session, ASSET), Ok(()));
+	assert_eq!(session.sandbox().balance_of(&ASSET, &contract), 5 * DRIP_AMOUNT);
+	assert_eq!(session.sandbox().balance_of(&ASSET, &CHARLIE), 5 * DRIP_AMOUNT);
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_asset_mints_minted_asset(mut session: Session) {
+	let _ = env_logger::try_init();
+	let supply_cap = 2 * DRIP_AMOUNT;
+	drink::deploy::<Pop, FaucetError>(
+		&mut session,
+		BundleProvider::local().unwrap(),
+		"new_with_minted_asset",
+		vec![
+			COOLDOWN.to_string(),
+			DRIP_AMOUNT.to_string(),
+			ASSET.to_string(),
+			"1".to_string(),
+			format!("AssetConfig {{ drip_amount: {DRIP_AMOUNT}, cooldown: {COOLDOWN} }}"),
+			format!("MintConfig {{ supply_cap: Some({supply_cap}) }}"),
+			// "Faucet Token", "FCT".
+			"AssetMetadata { name: 0x46617563657420546f6b656e, symbol: 0x464354, decimals: 10 }"
+				.to_string(),
+		],
+		NO_SALT,
+		Some(INIT_VALUE),
+	)
+	.unwrap();
+	assert_eq!(mint_config(&mut session, ASSET), Some(MintConfig { supply_cap: Some(supply_cap) }));
+	assert_eq!(start_stop(&mut session), Ok(()));
+
+	// The faucet holds none of the asset, drips are minted.
+	session.set_actor(BOB);
+	assert_eq!(drip_asset(&mut session, ASSET), Ok(()));
+	assert_eq!(
+		last_contract_event(&session),
+		Some((ASSET, DRIP_AMOUNT, BOB).encode().as_slice())
+	);
+	session.set_actor(CHARLIE);
+	assert_eq!(drip_asset(&mut session, ASSET), Ok(()));
+	assert_eq!(asset_total_dripped(&mut session, ASSET), supply_cap);
+	session.set_actor(ALICE);
+	assert_eq!(
+		drip_asset(&mut session, ASSET),
+		Err(FaucetError::SupplyCapReached { cap: supply_cap })
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn access_mode_restricts_eligibility(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+
+	assert_eq!(set_access_mode(&mut session, "Allowlist"), Ok(()));
+	assert_eq!(add_to_allowlist(&mut session, vec![BOB]), Ok(()));
+	session.set_actor(BOB);
+	assert_eq!(drip(&mut session), Ok(()));
+	session.set_actor(CHARLIE);
+	assert_eq!(drip(&mut session), Err(FaucetError::NotAllowlisted));
+
+	// Denylisted accounts cannot drip even if allowlisted, until their entry expires.
+	session.set_actor(ALICE);
+	assert_eq!(add_to_allowlist(&mut session, vec![CHARLIE]), Ok(()));
+	let expires_at = session.sandbox().block_number() + 5;
+	assert_eq!(add_to_denylist(&mut session, vec![CHARLIE], 7, Some(expires_at)), Ok(()));
+	session.set_actor(CHARLIE);
+	assert_eq!(
+		drip(&mut session),
+		Err(FaucetError::Denylisted { reason: 7, expires_at: Some(expires_at) })
+	);
+	session.sandbox().build_blocks(5);
+	assert_eq!(
+		drip(&mut session),
+		Err(FaucetError::Denylisted { reason: 7, expires_at: Some(expires_at) })
+	);
+	session.sandbox().build_blocks(1);
+	assert_eq!(drip(&mut session), Ok(()));
+
+	// In denylist mode, accounts do not need to be allowlisted.
+	session.set_actor(ALICE);
+	assert_eq!(set_access_mode(&mut session, "Denylist"), Ok(()));
+	assert_eq!(remove_from_allowlist(&mut session, vec![CHARLIE]), Ok(()));
+	assert_eq!(add_to_denylist(&mut session, vec![CHARLIE], 1, None), Ok(()));
+	session.set_actor(CHARLIE);
+	assert_eq!(
+		drip(&mut session),
+		Err(FaucetError::Denylisted { reason: 1, expires_at: None })
+	);
+	session.set_actor(ALICE);
+	assert_eq!(remove_from_denylist(&mut session, vec![CHARLIE]), Ok(()));
+	session.sandbox().build_blocks(COOLDOWN);
+	session.set_actor(CHARLIE);
+	assert_eq!(drip(&mut session), Ok(()));
+
+	// Only an `Operator` can manage the lists.
+	session.set_actor(BOB);
+	assert_eq!(add_to_allowlist(&mut session, vec![BOB]), Err(FaucetError::MissingRole));
+	assert_eq!(add_to_denylist(&mut session, vec![BOB], 1, None), Err(FaucetError::MissingRole));
+}
+
+#[drink::test(sandbox = Pop)]
+fn drip_with_voucher_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(start_stop(&mut session), Ok(()));
+	let attester = ecdsa::Pair::from_seed(&[1u8; 32]);
+	// Only an `Admin` can add attesters.
+	session.set_actor(BOB);
+	assert_eq!(add_attester(&mut session, &attester), Err(FaucetError::MissingRole));
+	session.set_actor(ALICE);
+	assert_eq!(add_attester(&mut session, &attester), Ok(()));
+
+	let expires_at = session.sandbox().block_number() + COOLDOWN;
+	let redeemed = voucher(BOB, 2 * DRIP_AMOUNT, expires_at, 0);
+	// Anyone can redeem a voucher on behalf of its beneficiary.
+	session.set_actor(CHARLIE);
+	assert_eq!(
+		drip_with_voucher(&mut session, &redeemed, [0u8; 65]),
+		Err(FaucetError::InvalidSignature)
+	);
+	let stranger = ecdsa::Pair::from_seed(&[2u8; 32]);
+	let signature = sign_voucher(&mut session, &stranger, &redeemed);
+	assert_eq!(
+		drip_with_voucher(&mut session, &redeemed, signature),
+		Err(FaucetError::UnknownAttester)
+	);
+	session.set_actor(BOB);
+	assert_eq!(drip_amount_of(&mut session, DRIP_AMOUNT / 2), Ok(()));
+	session.set_actor(CHARLIE);
+	let balance_before = session.sandbox().free_balance(&BOB);
+	let signature = sign_voucher(&mut session, &attester, &redeemed);
+	assert_eq!(drip_with_voucher(&mut session, &redeemed, signature), Ok(()));
+	assert_eq!(session.sandbox().free_balance(&BOB), balance_before + 2 * DRIP_AMOUNT);
+	// The voucher is followed by the full cooldown, even after a smaller drip.
+	session.set_actor(BOB);
+	let available_at = Instant::Block(session.sandbox().block_number() + COOLDOWN);
+	assert_eq!(drip(&mut session), Err(FaucetError::InCoolDown { available_at }));
+	session.set_actor(CHARLIE);
+	assert_eq!(
+		drip_with_voucher(&mut session, &redeemed, signature),
+		Err(FaucetError::VoucherAlreadyUsed)
+	);
+
+	// Vouchers can be redeemed up to their expiry block included.
+	let expired = voucher(BOB, DRIP_AMOUNT, expires_at, 1);
+	let signature = sign_voucher(&mut session, &attester, &expired);
+	let block = session.sandbox().block_number();
+	session.sandbox().build_blocks(expires_at + 1 - block);
+	assert_eq!(
+		drip_with_voucher(&mut session, &expired, signature),
+		Err(FaucetError::VoucherExpired { expired_at: expires_at })
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn withdraw_and_sweep_work(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	// Only a `Treasurer` can withdraw funds.
+	session.set_actor(BOB);
+	assert_eq!(withdraw(&mut session, DRIP_AMOUNT, BOB), Err(FaucetError::MissingRole));
+	assert_eq!(sweep(&mut session, BOB), Err(FaucetError::MissingRole));
+	session.set_actor(ALICE);
+	assert_eq!(grant_role(&mut session, "Treasurer", BOB), Ok(()));
+
+	session.set_actor(BOB);
+	let balance_before = session.sandbox().free_balance(&CHARLIE);
+	assert_eq!(withdraw(&mut session, DRIP_AMOUNT, CHARLIE), Ok(()));
+	assert_eq!(session.sandbox().free_balance(&CHARLIE), balance_before + DRIP_AMOUNT);
+	assert_eq!(
+		last_contract_event(&session),
+		Some((None::<TokenId>, DRIP_AMOUNT, CHARLIE).encode().as_slice())
+	);
+	assert!(matches!(
+		withdraw(&mut session, INIT_VALUE, CHARLIE),
+		Err(FaucetError::NotEnoughFunds { required, .. }) if required == INIT_VALUE + 2
+	));
+
+	// Sweeping withdraws everything but the minimum balance of the faucet.
+	let balance_before = session.sandbox().free_balance(&CHARLIE);
+	assert_eq!(sweep(&mut session, CHARLIE), Ok(()));
+	let swept = session.sandbox().free_balance(&CHARLIE) - balance_before;
+	assert!(swept > 0);
+	assert_eq!(
+		last_contract_event(&session),
+		Some((None::<TokenId>, swept, CHARLIE).encode().as_slice())
+	);
+	assert!(matches!(withdraw(&mut session, 1, CHARLIE), Err(FaucetError::NotEnoughFunds { .. })));
+}
+
+#[drink::test(sandbox = Pop)]
+fn withdraw_and_sweep_asset_work(mut session: Session) {
+	let _ = env_logger::try_init();
+	let contract = deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	// The asset is held by the faucet without being registered.
+	session.sandbox().create(&ASSET, &ALICE, 1).unwrap();
+	session.sandbox().mint_into(&ASSET, &contract, 10 * DRIP_AMOUNT).unwrap();
+	session.set_actor(BOB);
+	assert_eq!(
+		withdraw_asset(&mut session, ASSET, DRIP_AMOUNT, BOB),
+		Err(FaucetError::MissingRole)
+	);
+	assert_eq!(sweep_asset(&mut session, ASSET, BOB), Err(FaucetError::MissingRole));
+
+	session.set_actor(ALICE);
+	assert_eq!(withdraw_asset(&mut session, ASSET, DRIP_AMOUNT, CHARLIE), Ok(()));
+	assert_eq!(session.sandbox().balance_of(&ASSET, &CHARLIE), DRIP_AMOUNT);
+	assert_eq!(
+		last_contract_event(&session),
+		Some((Some(ASSET), DRIP_AMOUNT, CHARLIE).encode().as_slice())
+	);
+	assert_eq!(
+		withdraw_asset(&mut session, ASSET, 9 * DRIP_AMOUNT, CHARLIE),
+		Err(FaucetError::NotEnoughFunds {
+			available: 9 * DRIP_AMOUNT,
+			required: 9 * DRIP_AMOUNT + 2
+		})
+	);
+	// Sweeping leaves a balance of 2 units.
+	assert_eq!(sweep_asset(&mut session, ASSET, CHARLIE), Ok(()));
+	assert_eq!(session.sandbox().balance_of(&ASSET, &contract), 2);
+	assert_eq!(session.sandbox().balance_of(&ASSET, &CHARLIE), 10 * DRIP_AMOUNT - 2);
+}
+
+#[drink::test(sandbox = Pop)]
+fn set_cooldown_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+
+	session.set_actor(BOB);
+	assert_eq!(set_cooldown(&mut session, 1), Err(FaucetError::MissingRole));
+
+	session.set_actor(ALICE);
+	assert_eq!(set_cooldown(&mut session, 1), Ok(()));
+	assert_eq!(cooldown(&mut session), 1);
+	assert_eq!(
+		last_contract_event(&session),
+		Some((Timestamp::from(COOLDOWN), 1 as Timestamp).encode().as_slice())
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn set_drip_amount_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+
+	session.set_actor(BOB);
+	assert_eq!(set_drip_amount(&mut session, 2 * DRIP_AMOUNT), Err(FaucetError::MissingRole));
+
+	session.set_actor(ALICE);
+	assert_eq!(set_drip_amount(&mut session, 2 * DRIP_AMOUNT), Ok(()));
+	assert_eq!(drip_amount(&mut session), 2 * DRIP_AMOUNT);
+	assert_eq!(
+		last_contract_event(&session),
+		Some((DRIP_AMOUNT, 2 * DRIP_AMOUNT).encode().as_slice())
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn start_stop_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+
+	session.set_actor(BOB);
+	assert_eq!(start_stop(&mut session), Err(FaucetError::MissingRole));
+
+	session.set_actor(ALICE);
+	assert_eq!(start_stop(&mut session), Ok(()));
+	assert!(is_active(&mut session));
+	assert_eq!(last_contract_event(&session), Some((false, true).encode().as_slice()));
+}
+
+#[drink::test(sandbox = Pop)]
+fn operator_can_start_stop(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(grant_role(&mut session, "Operator", BOB), Ok(()));
+
+	session.set_actor(BOB);
+	assert_eq!(start_stop(&mut session), Ok(()));
+	assert!(is_active(&mut session));
+	// Operators cannot transfer ownership nor change the configuration.
+	assert_eq!(transfer_ownership(&mut session, BOB, None), Err(FaucetError::NotOwner));
+	assert_eq!(set_cooldown(&mut session, 1), Err(FaucetError::MissingRole));
+}
+
+#[drink::test(sandbox = Pop)]
+fn revoke_and_renounce_role_work(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(grant_role(&mut session, "Operator", BOB), Ok(()));
+	// Only an `Admin` can revoke roles.
+	session.set_actor(BOB);
+	assert_eq!(revoke_role(&mut session, "Operator", BOB), Err(FaucetError::MissingRole));
+
+	session.set_actor(ALICE);
+	assert_eq!(revoke_role(&mut session, "Operator", BOB), Ok(()));
+	assert_eq!(
+		last_contract_event(&session),
+		Some((Role::Operator, BOB, ALICE).encode().as_slice())
+	);
+	assert!(!has_role(&mut session, "Operator", BOB));
+	session.set_actor(BOB);
+	assert_eq!(start_stop(&mut session), Err(FaucetError::MissingRole));
+
+	// Accounts can give up their own roles.
+	session.set_actor(ALICE);
+	assert_eq!(grant_role(&mut session, "Operator", BOB), Ok(()));
+	session.set_actor(BOB);
+	assert_eq!(renounce_role(&mut session, "Operator"), Ok(()));
+	assert_eq!(
+		last_contract_event(&session),
+		Some((Role::Operator, BOB, BOB).encode().as_slice())
+	);
+	assert!(!has_role(&mut session, "Operator", BOB));
+	assert_eq!(renounce_role(&mut session, "Operator"), Err(FaucetError::MissingRole));
+	assert_eq!(start_stop(&mut session), Err(FaucetError::MissingRole));
+}
+
+#[drink::test(sandbox = Pop)]
+fn transfer_ownership_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+
+	session.set_actor(BOB);
+	assert_eq!(transfer_ownership(&mut session, BOB, None), Err(FaucetError::NotOwner));
+
+	session.set_actor(ALICE);
+	assert_eq!(transfer_ownership(&mut session, BOB, None), Ok(()));
+	// Ownership only changes once accepted.
+	assert_eq!(owner(&mut session), Some(contract_account(&ALICE)));
+	assert_eq!(
+		pending_owner(&mut session),
+		Some(PendingOwnership { owner: contract_account(&BOB), expires_at: None })
+	);
+	session.set_actor(CHARLIE);
+	assert_eq!(accept_ownership(&mut session), Err(FaucetError::NotPendingOwner));
+
+	session.set_actor(BOB);
+	assert_eq!(accept_ownership(&mut session), Ok(()));
+	assert_eq!(owner(&mut session), Some(contract_account(&BOB)));
+	assert_eq!(pending_owner(&mut session), None);
+	assert_eq!(last_contract_event(&session), Some((Some(ALICE), BOB).encode().as_slice()));
+	assert_eq!(accept_ownership(&mut session), Err(FaucetError::NoPendingOwner));
+}
+
+#[drink::test(sandbox = Pop)]
+fn transfer_ownership_expires(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	let expires_at = session.sandbox().block_number() + 1;
+	assert_eq!(transfer_ownership(&mut session, BOB, Some(expires_at)), Ok(()));
+
+	session.sandbox().build_blocks(2);
+	session.set_actor(BOB);
+	assert_eq!(
+		accept_ownership(&mut session),
+		Err(FaucetError::OwnershipTransferExpired { expired_at: expires_at })
+	);
+	assert_eq!(owner(&mut session), Some(contract_account(&ALICE)));
+}
+
+#[drink::test(sandbox = Pop)]
+fn cancel_ownership_transfer_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+	assert_eq!(cancel_ownership_transfer(&mut session), Err(FaucetError::NoPendingOwner));
+	assert_eq!(transfer_ownership(&mut session, BOB, None), Ok(()));
+
+	session.set_actor(BOB);
+	assert_eq!(cancel_ownership_transfer(&mut session), Err(FaucetError::NotOwner));
+
+	session.set_actor(ALICE);
+	assert_eq!(cancel_ownership_transfer(&mut session), Ok(()));
+	assert_eq!(pending_owner(&mut session), None);
+	assert_eq!(last_contract_event(&session), Some(BOB.encode().as_slice()));
+
+	session.set_actor(BOB);
+	assert_eq!(accept_ownership(&mut session), Err(FaucetError::NoPendingOwner));
+}
+
+#[drink::test(sandbox = Pop)]
+fn remove_ownership_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy(&mut session, COOLDOWN, DRIP_AMOUNT, INIT_VALUE).unwrap();
+
+	session.set_actor(BOB);
+	assert_eq!(remove_ownership(&mut session), Err(FaucetError::NotOwner));
+
+	session.set_actor(ALICE);
+	assert_eq!(remove_ownership(&mut session), Ok(()));
+	assert_eq!(owner(&mut session), None);
+	assert_eq!(last_contract_event(&session), Some(ALICE.encode().as_slice()));
+	// Former owner lost every permission.
+	assert_eq!(start_stop(&mut session), Err(FaucetError::MissingRole));
+	assert_eq!(transfer_ownership(&mut session, ALICE, None), Err(FaucetError::NotOwner));
+}
+
+fn contract_account(account: &AccountId) -> ink::primitives::AccountId {
+	let bytes: &[u8; 32] = account.as_ref();
+	ink::primitives::AccountId::from(*bytes)
+}
+
+fn deploy(
+	session: &mut Session<Pop>,
+	cooldown: BlockNumber,
+	drip_amount: Balance,
+	value: Balance,
+) -> Result<AccountId, FaucetError> {
+	drink::deploy::<Pop, FaucetError>(
+		session,
+		BundleProvider::local().unwrap(),
+		"new",
+		vec![cooldown.to_string(), drip_amount.to_string()],
+		NO_SALT,
+		Some(value),
+	)
+}
+
+fn deploy_with_cooldown_unit(
+	session: &mut Session<Pop>,
+	cooldown: Timestamp,
+	drip_amount: Balance,
+	cooldown_unit: &str,
+	value: Balance,
+) -> Result<AccountId, FaucetError> {
+	drink::deploy::<Pop, FaucetError>(
+		session,
+		BundleProvider::local().unwrap(),
+		"new_with_cooldown_unit",
+		vec![cooldown.to_string(), drip_amount.to_string(), cooldown_unit.to_string()],
+		NO_SALT,
+		Some(value),
+	)
+}
+
+fn cooldown(session: &mut Session<Pop>) -> Timestamp {
+	call::<Pop, Timestamp, FaucetError>(session, "cooldown", vec![], None).unwrap()
+}
+
+fn drip_amount(session: &mut Session<Pop>) -> Balance {
+	call::<Pop, Balance, FaucetError>(session, "drip_amount", vec![], None).unwrap()
+}
+
+fn is_active(session: &mut Session<Pop>) -> bool {
+	call::<Pop, bool, FaucetError>(session, "is_active", vec![], None).unwrap()
+}
+
+fn owner(session: &mut Session<Pop>) -> Option<ink::primitives::AccountId> {
+	call::<Pop, Option<ink::primitives::AccountId>, FaucetError>(session, "owner", vec![], None)
+		.unwrap()
+}
+
+fn pending_owner(session: &mut Session<Pop>) -> Option<PendingOwnership> {
+	call::<Pop, Option<PendingOwnership>, FaucetError>(session, "pending_owner", vec![], None)
+		.unwrap()
+}
+
+fn start_stop(session: &mut Session<Pop>) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "start_stop", vec![], None)
+}
+
+fn set_cooldown(session: &mut Session<Pop>, cooldown: Timestamp) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "set_cooldown", vec![cooldown.to_string()], None)
+}
+
+fn set_drip_amount(session: &mut Session<Pop>, drip_amount: Balance) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "set_drip_amount", vec![drip_amount.to_string()], None)
+}
+
+fn grant_role(
+	session: &mut Session<Pop>,
+	role: &str,
+	account: AccountId,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"grant_role",
+		vec![role.to_string(), account.to_string()],
+		None,
+	)
+}
+
+fn revoke_role(
+	session: &mut Session<Pop>,
+	role: &str,
+	account: AccountId,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"revoke_role",
+		vec![role.to_string(), account.to_string()],
+		None,
+	)
+}
+
+fn renounce_role(session: &mut Session<Pop>, role: &str) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "renounce_role", vec![role.to_string()], None)
+}
+
+fn has_role(session: &mut Session<Pop>, role: &str, account: AccountId) -> bool {
+	call::<Pop, bool, FaucetError>(
+		session,
+		"has_role",
+		vec![role.to_string(), account.to_string()],
+		None,
+	)
+	.unwrap()
+}
+
+fn transfer_ownership(
+	session: &mut Session<Pop>,
+	new_owner: AccountId,
+	expires_at: Option<BlockNumber>,
+) -> Result<(), FaucetError> {
+	let expires_at = match expires_at {
+		Some(block) => format!("Some({block})"),
+		None => "None".to_string(),
+	};
+	call::<Pop, (), FaucetError>(
+		session,
+		"transfer_ownership",
+		vec![new_owner.to_string(), expires_at],
+		None,
+	)
+}
+
+fn accept_ownership(session: &mut Session<Pop>) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "accept_ownership", vec![], None)
+}
+
+fn cancel_ownership_transfer(session: &mut Session<Pop>) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "cancel_ownership_transfer", vec![], None)
+}
+
+fn remove_ownership(session: &mut Session<Pop>) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "remove_ownership", vec![], None)
+}
+
+fn last_request_of(session: &mut Session<Pop>, account: AccountId) -> Option<Instant> {
+	call::<Pop, Option<Instant>, FaucetError>(
+		session,
+		"last_request_of",
+		vec![account.to_string()],
+		None,
+	)
+	.unwrap()
+}
+
+fn eligibility(session: &mut Session<Pop>, account: AccountId) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "eligibility", vec![account.to_string()], None)
+}
+
+fn drip(session: &mut Session<Pop>) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "drip", vec![], None)
+}
+
+fn drip_to(session: &mut Session<Pop>, beneficiary: AccountId) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "drip_to", vec![beneficiary.to_string()], None)
+}
+
+fn remaining_quota(session: &mut Session<Pop>, account: AccountId) -> RemainingQuota {
+	call::<Pop, RemainingQuota, FaucetError>(
+		session,
+		"remaining_quota",
+		vec![account.to_string()],
+		None,
+	)
+	.unwrap()
+}
+
+fn set_global_budget(session: &mut Session<Pop>, budget: &str) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "set_global_budget", vec![budget.to_string()], None)
+}
+
+fn set_quotas(session: &mut Session<Pop>, quotas: &str) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "set_quotas", vec![quotas.to_string()], None)
+}
+
+fn drip_amount_of(session: &mut Session<Pop>, amount: Balance) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "drip_amount_of", vec![amount.to_string()], None)
+}
+
+fn set_cooldown_unit(
+	session: &mut Session<Pop>,
+	cooldown_unit: &str,
+	cooldown: Timestamp,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"set_cooldown_unit",
+		vec![cooldown_unit.to_string(), cooldown.to_string()],
+		None,
+	)
+}
+
+fn migrate_last_requests(
+	session: &mut Session<Pop>,
+	accounts: Vec<AccountId>,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"migrate_last_requests",
+		vec![account_list(&accounts)],
+		None,
+	)
+}
+
+fn set_max_request_amount(
+	session: &mut Session<Pop>,
+	max_request_amount: Balance,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"set_max_request_amount",
+		vec![max_request_amount.to_string()],
+		None,
+	)
+}
+
+fn allowance_of(session: &mut Session<Pop>, account: AccountId) -> Option<Balance> {
+	call::<Pop, Option<Balance>, FaucetError>(
+		session,
+		"allowance_of",
+		vec![account.to_string()],
+		None,
+	)
+	.unwrap()
+}
+
+fn effective_drip_amount(session: &mut Session<Pop>) -> Balance {
+	call::<Pop, Balance, FaucetError>(session, "effective_drip_amount", vec![], None).unwrap()
+}
+
+fn set_drip_curve(session: &mut Session<Pop>, drip_curve: &str) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "set_drip_curve", vec![drip_curve.to_string()], None)
+}
+
+fn set_rate_limit(session: &mut Session<Pop>, rate_limit: &str) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "set_rate_limit", vec![rate_limit.to_string()], None)
+}
+
+fn set_low_water_mark(
+	session: &mut Session<Pop>,
+	low_water_mark: &str,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"set_low_water_mark",
+		vec![low_water_mark.to_string()],
+		None,
+	)
+}
+
+fn mint_config(session: &mut Session<Pop>, asset_id: TokenId) -> Option<MintConfig> {
+	call::<Pop, Option<MintConfig>, FaucetError>(
+		session,
+		"mint_config",
+		vec![asset_id.to_string()],
+		None,
+	)
+	.unwrap()
+}
+
+fn drip_asset(session: &mut Session<Pop>, asset_id: TokenId) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "drip_asset", vec![asset_id.to_string()], None)
+}
+
+fn last_asset_request_of(
+	session: &mut Session<Pop>,
+	asset_id: TokenId,
+	account: AccountId,
+) -> Option<Instant> {
+	call::<Pop, Option<Instant>, FaucetError>(
+		session,
+		"last_asset_request_of",
+		vec![asset_id.to_string(), account.to_string()],
+		None,
+	)
+	.unwrap()
+}
+
+fn asset_total_dripped(session: &mut Session<Pop>, asset_id: TokenId) -> Balance {
+	call::<Pop, Balance, FaucetError>(
+		session,
+		"asset_total_dripped",
+		vec![asset_id.to_string()],
+		None,
+	)
+	.unwrap()
+}
+
+fn fund(session: &mut Session<Pop>, value: Balance) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "fund", vec![], Some(value))
+}
+
+fn total_received(session: &mut Session<Pop>) -> Balance {
+	call::<Pop, Balance, FaucetError>(session, "total_received", vec![], None).unwrap()
+}
+
+fn total_dripped(session: &mut Session<Pop>) -> Balance {
+	call::<Pop, Balance, FaucetError>(session, "total_dripped", vec![], None).unwrap()
+}
+
+fn drip_count(session: &mut Session<Pop>) -> u64 {
+	call::<Pop, u64, FaucetError>(session, "drip_count", vec![], None).unwrap()
+}
+
+fn unique_recipients(session: &mut Session<Pop>) -> u32 {
+	call::<Pop, u32, FaucetError>(session, "unique_recipients", vec![], None).unwrap()
+}
+
+fn runway(session: &mut Session<Pop>) -> Runway {
+	call::<Pop, Runway, FaucetError>(session, "runway", vec![], None).unwrap()
+}
+
+fn donations_of(session: &mut Session<Pop>, account: AccountId) -> Balance {
+	call::<Pop, Balance, FaucetError>(session, "donations_of", vec![account.to_string()], None)
+		.unwrap()
+}
+
+fn top_donors(session: &mut Session<Pop>) -> Vec<(ink::primitives::AccountId, Balance)> {
+	call::<Pop, Vec<(ink::primitives::AccountId, Balance)>, FaucetError>(
+		session,
+		"top_donors",
+		vec![],
+		None,
+	)
+	.unwrap()
+}
+
+fn register_asset(
+	session: &mut Session<Pop>,
+	asset_id: TokenId,
+	drip_amount: Balance,
+	cooldown: Timestamp,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"register_asset",
+		vec![asset_id.to_string(), drip_amount.to_string(), cooldown.to_string()],
+		None,
+	)
+}
+
+fn set_refill(
+	session: &mut Session<Pop>,
+	asset_id: TokenId,
+	refill: &str,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"set_refill",
+		vec![asset_id.to_string(), refill.to_string()],
+		None,
+	)
+}
+
+fn refill(session: &mut Session<Pop>, asset_id: TokenId) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "refill", vec![asset_id.to_string()], None)
+}
+
+fn withdraw(
+	session: &mut Session<Pop>,
+	amount: Balance,
+	to: AccountId,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"withdraw",
+		vec![amount.to_string(), to.to_string()],
+		None,
+	)
+}
+
+fn sweep(session: &mut Session<Pop>, to: AccountId) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "sweep", vec![to.to_string()], None)
+}
+
+fn withdraw_asset(
+	session: &mut Session<Pop>,
+	asset_id: TokenId,
+	amount: Balance,
+	to: AccountId,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"withdraw_asset",
+		vec![asset_id.to_string(), amount.to_string(), to.to_string()],
+		None,
+	)
+}
+
+fn sweep_asset(
+	session: &mut Session<Pop>,
+	asset_id: TokenId,
+	to: AccountId,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"sweep_asset",
+		vec![asset_id.to_string(), to.to_string()],
+		None,
+	)
+}
+
+fn set_cooldown_caller(
+	session: &mut Session<Pop>,
+	cooldown_caller: bool,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"set_cooldown_caller",
+		vec![cooldown_caller.to_string()],
+		None,
+	)
+}
+
+fn set_access_mode(session: &mut Session<Pop>, mode: &str) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "set_access_mode", vec![mode.to_string()], None)
+}
+
+fn add_to_allowlist(
+	session: &mut Session<Pop>,
+	accounts: Vec<AccountId>,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "add_to_allowlist", vec![account_list(&accounts)], None)
+}
+
+fn remove_from_allowlist(
+	session: &mut Session<Pop>,
+	accounts: Vec<AccountId>,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"remove_from_allowlist",
+		vec![account_list(&accounts)],
+		None,
+	)
+}
+
+fn add_to_denylist(
+	session: &mut Session<Pop>,
+	accounts: Vec<AccountId>,
+	reason: u32,
+	expires_at: Option<BlockNumber>,
+) -> Result<(), FaucetError> {
+	let expires_at = match expires_at {
+		Some(block) => format!("Some({block})"),
+		None => "None".to_string(),
+	};
+	call::<Pop, (), FaucetError>(
+		session,
+		"add_to_denylist",
+		vec![account_list(&accounts), reason.to_string(), expires_at],
+		None,
+	)
+}
+
+fn remove_from_denylist(
+	session: &mut Session<Pop>,
+	accounts: Vec<AccountId>,
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"remove_from_denylist",
+		vec![account_list(&accounts)],
+		None,
+	)
+}
+
+fn add_attester(session: &mut Session<Pop>, attester: &ecdsa::Pair) -> Result<(), FaucetError> {
+	let attester = hex(attester.public().as_ref());
+	call::<Pop, (), FaucetError>(session, "add_attester", vec![attester], None)
+}
+
+fn voucher(beneficiary: AccountId, amount: Balance, expires_at: BlockNumber, nonce: u64) -> String {
+	format!(
+		"Voucher {{ beneficiary: {beneficiary}, amount: {amount}, expires_at: {expires_at}, \
+		 nonce: {nonce} }}"
+	)
+}
+
+fn sign_voucher(session: &mut Session<Pop>, attester: &ecdsa::Pair, voucher: &str) -> [u8; 65] {
+	let hash = call::<Pop, [u8; 32], FaucetError>(
+		session,
+		"voucher_hash",
+		vec![voucher.to_string()],
+		None,
+	)
+	.unwrap();
+	let mut signature = [0u8; 65];
+	signature.copy_from_slice(attester.sign_prehashed(&hash).as_ref());
+	signature
+}
+
+fn drip_with_voucher(
+	session: &mut Session<Pop>,
+	voucher: &str,
+	signature: [u8; 65],
+) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(
+		session,
+		"drip_with_voucher",
+		vec![voucher.to_string(), hex(&signature)],
+		None,
+	)
+}
+
+fn drip_to_location(session: &mut Session<Pop>, location: &str) -> Result<(), FaucetError> {
+	call::<Pop, (), FaucetError>(session, "drip_to_location", vec![location.to_string()], None)
+}
+
+fn account_list(accounts: &[AccountId]) -> String {
+	let accounts = accounts.iter().map(|account| account.to_string()).collect::<Vec<_>>();
+	format!("[{}]", accounts.join(","))
+}
+
+fn hex(bytes: &[u8]) -> String {
+	format!("0x{}", bytes.iter().map(|byte| format!("{byte:02x}")).collect::<String>())
+}